@@ -0,0 +1,197 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AncientSiteKind {
+    Ruins,
+    Artefact,
+    Derelict,
+    Installation,
+}
+
+impl AncientSiteKind {
+    /// The kinds of remains, each with six features rolled on 1D.
+    pub fn features(&self) -> [&'static str; 6] {
+        match self {
+            AncientSiteKind::Ruins => [
+                "a buried city",
+                "a field of toppled monoliths",
+                "an underground vault complex",
+                "a pyramid of unknown purpose",
+                "a shattered spaceport",
+                "a sealed temple",
+            ],
+            AncientSiteKind::Artefact => [
+                "a cache of inert devices",
+                "a single functioning machine",
+                "an archive of unreadable data crystals",
+                "a weapon of unknown design",
+                "a navigation beacon still transmitting",
+                "a sculpture that hums when touched",
+            ],
+            AncientSiteKind::Derelict => [
+                "a drifting starship hull",
+                "a gutted orbital station",
+                "a swarm of dormant probes",
+                "a silent warship",
+                "a wrecked colony ship",
+                "a hollowed out asteroid habitat",
+            ],
+            AncientSiteKind::Installation => [
+                "a planetary defence battery",
+                "a weather control station",
+                "an automated factory",
+                "a gravitational anomaly generator",
+                "a stasis facility",
+                "a terraforming engine",
+            ],
+        }
+    }
+
+    /// How much more dangerous this kind of site is than the others.
+    pub fn danger_dm(&self) -> i32 {
+        match self {
+            AncientSiteKind::Ruins => 0,
+            AncientSiteKind::Artefact => -1,
+            AncientSiteKind::Derelict => 1,
+            AncientSiteKind::Installation => 2,
+        }
+    }
+}
+
+impl Display for AncientSiteKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AncientSiteKind::Ruins => write!(f, "Ruins"),
+            AncientSiteKind::Artefact => write!(f, "Artefact"),
+            AncientSiteKind::Derelict => write!(f, "Derelict"),
+            AncientSiteKind::Installation => write!(f, "Installation"),
+        }
+    }
+}
+
+pub const PRECURSORS: [&str; 6] = [
+    "the Ancients",
+    "an unknown precursor race",
+    "a lost human colony",
+    "a forgotten interstellar empire",
+    "a long dead native civilisation",
+    "a vanished sophont species",
+];
+
+pub const CONDITIONS: [&str; 4] = ["pristine", "largely intact", "damaged", "almost destroyed"];
+
+/// Remains left by someone long gone.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AncientSite {
+    pub kind: AncientSiteKind,
+    pub feature: &'static str,
+    pub builders: &'static str,
+    pub condition: &'static str,
+    /// Thousands of years since the builders left.
+    pub age: i32,
+    /// How likely the site is to hurt visitors, from 0 (harmless) to 10 (deadly).
+    pub danger: i32,
+    /// Major sites earn the world the An trade code.
+    pub major: bool,
+}
+
+impl AncientSite {
+    /// Rolls a site of the given kind.
+    pub fn new(rng: &mut Dice, kind: AncientSiteKind, major: bool) -> Self {
+        let feature = kind.features()[rng.roll(1, 6, -1) as usize];
+        let builders = rng.roll(1, 6, -1) as usize;
+        let condition = rng.roll(1, 4, -1) as usize;
+        // The Ancients all vanished together in the Final War
+        let age = match builders {
+            0 => 300,
+            _ => rng.roll(1, 6, 0) * rng.roll(1, 6, 0) * 5,
+        };
+        // Better preserved sites still have working defences
+        let condition_dm = match condition {
+            0 => 2,
+            1 => 1,
+            _ => 0,
+        };
+        let danger = rng
+            .roll(2, 6, -4 + kind.danger_dm() + condition_dm)
+            .clamp(0, 10);
+        Self {
+            kind,
+            feature,
+            builders: PRECURSORS[builders],
+            condition: CONDITIONS[condition],
+            age,
+            danger,
+            major,
+        }
+    }
+
+    /// The travel zone a referee should consider for the site's danger alone.
+    pub fn travel_zone(&self) -> TravelZone {
+        match self.danger {
+            8.. => TravelZone::Red,
+            5..=7 => TravelZone::Amber,
+            _ => TravelZone::Green,
+        }
+    }
+
+    /// A one sentence summary of the site.
+    pub fn description(&self) -> String {
+        let danger = match self.danger {
+            0..=2 => "harmless",
+            3..=4 => "somewhat hazardous",
+            5..=7 => "dangerous",
+            _ => "deadly",
+        };
+        let feature = self.feature.to_string();
+        let mut chars = feature.chars();
+        let feature = match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect(),
+            None => feature,
+        };
+        format!(
+            "{feature} left by {} some {} thousand years ago, now {} and {danger}.",
+            self.builders, self.age, self.condition
+        )
+    }
+}
+
+impl Display for AncientSite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (danger {}): {}",
+            self.kind,
+            self.danger,
+            self.description()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_danger_sets_zone() {
+        let mut rng = Seed::new(String::from("test")).to_rng();
+        let mut site = AncientSite::new(&mut rng, AncientSiteKind::Ruins, true);
+        site.danger = 9;
+        assert_eq!(site.travel_zone(), TravelZone::Red);
+        site.danger = 2;
+        assert_eq!(site.travel_zone(), TravelZone::Green);
+        assert!(site.description().starts_with(char::is_uppercase));
+    }
+
+    #[test]
+    fn test_ancients_left_together() {
+        let seed = Seed::new(String::from("test"));
+        for i in 0..100 {
+            let mut rng = seed.subseed(vec![i]).to_rng();
+            let site = AncientSite::new(&mut rng, AncientSiteKind::Ruins, false);
+            assert_eq!(site.builders == PRECURSORS[0], site.age == 300);
+        }
+    }
+}
@@ -0,0 +1,469 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// How far native life has evolved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LifeComplexity {
+    None,
+    Microbial,
+    Simple,
+    Complex,
+    Advanced,
+}
+
+impl LifeComplexity {
+    fn from_rating(rating: i32) -> Self {
+        match rating {
+            ..=0 => LifeComplexity::None,
+            1..=2 => LifeComplexity::Microbial,
+            3..=5 => LifeComplexity::Simple,
+            6..=8 => LifeComplexity::Complex,
+            _ => LifeComplexity::Advanced,
+        }
+    }
+}
+
+impl Display for LifeComplexity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LifeComplexity::None => write!(f, "None"),
+            LifeComplexity::Microbial => write!(f, "Microbial"),
+            LifeComplexity::Simple => write!(f, "Simple"),
+            LifeComplexity::Complex => write!(f, "Complex"),
+            LifeComplexity::Advanced => write!(f, "Advanced"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Terrain {
+    Plains,
+    Forest,
+    Jungle,
+    Desert,
+    Mountains,
+    Swamp,
+    Ocean,
+    Ice,
+}
+
+impl Terrain {
+    pub const ALL: [Terrain; 8] = [
+        Terrain::Plains,
+        Terrain::Forest,
+        Terrain::Jungle,
+        Terrain::Desert,
+        Terrain::Mountains,
+        Terrain::Swamp,
+        Terrain::Ocean,
+        Terrain::Ice,
+    ];
+
+    /// Terrains found on a world with some land, sea or air.
+    pub fn on(world: &World) -> Vec<Terrain> {
+        Terrain::ALL
+            .into_iter()
+            .filter(|terrain| match terrain {
+                Terrain::Plains => world.size > 0 && world.hydrographics < 10,
+                Terrain::Mountains => world.size > 0 && world.hydrographics < 10,
+                Terrain::Forest => {
+                    (4..=9).contains(&world.atmosphere) && (3..=8).contains(&world.hydrographics)
+                }
+                Terrain::Jungle => world.is_hot() && (4..=9).contains(&world.hydrographics),
+                Terrain::Desert => world.hydrographics <= 3 && world.size > 0,
+                Terrain::Swamp => (5..=9).contains(&world.hydrographics) && world.atmosphere >= 4,
+                Terrain::Ocean => world.hydrographics > 0,
+                Terrain::Ice => world.is_frozen() || world.is_cold() || world.is_ice_capped(),
+            })
+            .collect()
+    }
+
+    /// Shift on the animal size roll; open country and seas grow larger animals.
+    fn size_dm(&self) -> i32 {
+        match self {
+            Terrain::Plains => 0,
+            Terrain::Forest => -2,
+            Terrain::Jungle => -3,
+            Terrain::Desert => -3,
+            Terrain::Mountains => 0,
+            Terrain::Swamp => 1,
+            Terrain::Ocean => 2,
+            Terrain::Ice => 0,
+        }
+    }
+
+    /// Shift on the behaviour roll; barren terrain favours hunters and scavengers.
+    fn behaviour_dm(&self) -> i32 {
+        match self {
+            Terrain::Plains => 2,
+            Terrain::Forest => 0,
+            Terrain::Jungle => -1,
+            Terrain::Desert => -2,
+            Terrain::Mountains => -1,
+            Terrain::Swamp => 0,
+            Terrain::Ocean => 1,
+            Terrain::Ice => -2,
+        }
+    }
+}
+
+impl Display for Terrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Terrain::Plains => write!(f, "Plains"),
+            Terrain::Forest => write!(f, "Forest"),
+            Terrain::Jungle => write!(f, "Jungle"),
+            Terrain::Desert => write!(f, "Desert"),
+            Terrain::Mountains => write!(f, "Mountains"),
+            Terrain::Swamp => write!(f, "Swamp"),
+            Terrain::Ocean => write!(f, "Ocean"),
+            Terrain::Ice => write!(f, "Ice"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Diet {
+    Herbivore,
+    Omnivore,
+    Carnivore,
+    Scavenger,
+}
+
+impl Display for Diet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diet::Herbivore => write!(f, "Herbivore"),
+            Diet::Omnivore => write!(f, "Omnivore"),
+            Diet::Carnivore => write!(f, "Carnivore"),
+            Diet::Scavenger => write!(f, "Scavenger"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Behaviour {
+    Filter,
+    Intermittent,
+    Grazer,
+    Gatherer,
+    Hunter,
+    Eater,
+    Pouncer,
+    Chaser,
+    Trapper,
+    Siren,
+    Killer,
+    Intimidator,
+    Hijacker,
+    CarrionEater,
+    Reducer,
+}
+
+impl Behaviour {
+    pub fn diet(&self) -> Diet {
+        match self {
+            Behaviour::Filter | Behaviour::Intermittent | Behaviour::Grazer => Diet::Herbivore,
+            Behaviour::Gatherer | Behaviour::Hunter | Behaviour::Eater => Diet::Omnivore,
+            Behaviour::Pouncer
+            | Behaviour::Chaser
+            | Behaviour::Trapper
+            | Behaviour::Siren
+            | Behaviour::Killer => Diet::Carnivore,
+            Behaviour::Intimidator
+            | Behaviour::Hijacker
+            | Behaviour::CarrionEater
+            | Behaviour::Reducer => Diet::Scavenger,
+        }
+    }
+
+    /// Grazers sit in the middle of the 2D range, hunters and scavengers at the ends.
+    fn from_roll(roll: i32) -> Self {
+        match roll {
+            ..=1 => Behaviour::Killer,
+            2 => Behaviour::Siren,
+            3 => Behaviour::Trapper,
+            4 => Behaviour::Pouncer,
+            5 => Behaviour::Chaser,
+            6 => Behaviour::Gatherer,
+            7 | 8 => Behaviour::Grazer,
+            9 => Behaviour::Intermittent,
+            10 => Behaviour::Hunter,
+            11 => Behaviour::Eater,
+            12 => Behaviour::Filter,
+            13 => Behaviour::CarrionEater,
+            14 => Behaviour::Hijacker,
+            15 => Behaviour::Intimidator,
+            _ => Behaviour::Reducer,
+        }
+    }
+}
+
+impl Display for Behaviour {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Behaviour::Filter => write!(f, "Filter"),
+            Behaviour::Intermittent => write!(f, "Intermittent"),
+            Behaviour::Grazer => write!(f, "Grazer"),
+            Behaviour::Gatherer => write!(f, "Gatherer"),
+            Behaviour::Hunter => write!(f, "Hunter"),
+            Behaviour::Eater => write!(f, "Eater"),
+            Behaviour::Pouncer => write!(f, "Pouncer"),
+            Behaviour::Chaser => write!(f, "Chaser"),
+            Behaviour::Trapper => write!(f, "Trapper"),
+            Behaviour::Siren => write!(f, "Siren"),
+            Behaviour::Killer => write!(f, "Killer"),
+            Behaviour::Intimidator => write!(f, "Intimidator"),
+            Behaviour::Hijacker => write!(f, "Hijacker"),
+            Behaviour::CarrionEater => write!(f, "Carrion-eater"),
+            Behaviour::Reducer => write!(f, "Reducer"),
+        }
+    }
+}
+
+/// One line of an animal encounter table.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AnimalEncounter {
+    /// The 1D roll that selects this line.
+    pub roll: i32,
+    pub behaviour: Behaviour,
+    /// Typical adult mass, in kilograms.
+    pub mass: i32,
+    /// Dice of animals met at once.
+    pub quantity: usize,
+}
+
+impl Display for AnimalEncounter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}D {} {} ({} kg)",
+            self.roll,
+            self.quantity,
+            self.behaviour.diet(),
+            self.behaviour,
+            self.mass
+        )
+    }
+}
+
+/// Native life on a world.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Biosphere {
+    seed: Seed,
+    /// How much life there is, from 0 (none) to 15 (teeming).
+    pub biomass: i32,
+    pub complexity: LifeComplexity,
+    /// How well humans get on with native life, from 0 (poisonous) to 10 (edible).
+    pub compatibility: i32,
+    pub terrain: Vec<Terrain>,
+}
+
+impl Biosphere {
+    pub fn new(sys: &System, world: &World) -> Self {
+        // Worlds sharing an orbit, such as a gas giant's moons, differ by their
+        // physical profile; settling a world does not change its native life
+        let seed = sys.seed.subseed(vec![
+            String::from("biosphere"),
+            world.orbit.to_string(),
+            world.size.to_string(),
+            world.atmosphere.to_string(),
+            world.hydrographics.to_string(),
+        ]);
+        let mut rng = seed.to_rng();
+        let terrain = Terrain::on(world);
+
+        let atmosphere_dm = match world.atmosphere {
+            0 => -12,
+            1 => -4,
+            2 | 3 => -2,
+            4..=9 => 2,
+            10 | 13 | 14 => 0,
+            _ => -3,
+        };
+        let hydrographics_dm = match world.hydrographics {
+            0 => -4,
+            1..=3 => -2,
+            6..=8 => 1,
+            9.. => 2,
+            _ => 0,
+        };
+        let temperature_dm = if world.is_frozen() {
+            -4
+        } else if world.is_hot() || world.is_cold() {
+            -1
+        } else {
+            0
+        };
+        // Hot young stars have had little time for life; dim ones give little light
+        let star = &sys.stars[0];
+        let star_dm = match star {
+            Star::Star(Class::O | Class::B | Class::A, _, _) => -4,
+            Star::Star(Class::M, _, _) => -1,
+            Star::Star(..) => 0,
+            Star::Dwarf(_) | Star::BrownDwarf => -4,
+        };
+
+        let mut biomass = rng
+            .roll(
+                2,
+                6,
+                -7 + atmosphere_dm + hydrographics_dm + temperature_dm + star_dm,
+            )
+            .clamp(0, 15);
+        if world.is_garden() {
+            biomass = biomass.max(4)
+        }
+        if world.is_hellworld() {
+            biomass = biomass.min(2)
+        }
+
+        let complexity = match biomass {
+            0 => LifeComplexity::None,
+            _ => LifeComplexity::from_rating(rng.roll(2, 6, -7 + biomass / 2 + star_dm / 2).max(1)),
+        };
+
+        let compatibility = match biomass {
+            0 => 0,
+            _ => {
+                let taint = match world.atmosphere {
+                    6 | 8 => 2,
+                    5 => 1,
+                    4 | 7 | 9 => -1,
+                    _ => -4,
+                };
+                let garden = if world.is_garden() { 2 } else { 0 };
+                match world.is_hellworld() {
+                    true => 0,
+                    false => rng.roll(2, 6, -4 + taint + garden).clamp(0, 10),
+                }
+            }
+        };
+
+        Self {
+            seed,
+            biomass,
+            complexity,
+            compatibility,
+            terrain,
+        }
+    }
+
+    pub fn has_life(&self) -> bool {
+        self.biomass > 0
+    }
+
+    /// A 1D animal encounter table for one kind of terrain.
+    ///
+    /// Worlds without animals, or without that terrain, have an empty table.
+    pub fn encounter_table(&self, terrain: Terrain) -> Vec<AnimalEncounter> {
+        if self.complexity < LifeComplexity::Simple || !self.terrain.contains(&terrain) {
+            return vec![];
+        }
+        let mut rng = self.seed.subseed(vec![terrain.to_string()]).to_rng();
+        let complexity_dm = match self.complexity {
+            LifeComplexity::Advanced => 2,
+            LifeComplexity::Complex => 1,
+            _ => -2,
+        };
+        (1..=6)
+            .map(|roll| {
+                let behaviour =
+                    Behaviour::from_roll(rng.roll(2, 6, terrain.behaviour_dm() + (roll - 3) / 2));
+                let size = rng.roll(2, 6, terrain.size_dm() + complexity_dm);
+                let mass = match size {
+                    ..=2 => 1,
+                    3 => 3,
+                    4 => 6,
+                    5 => 12,
+                    6 => 25,
+                    7 => 50,
+                    8 => 100,
+                    9 => 200,
+                    10 => 400,
+                    11 => 800,
+                    12 => 1600,
+                    _ => 3200,
+                };
+                let quantity = match behaviour.diet() {
+                    Diet::Herbivore => rng.roll(1, 3, 0),
+                    Diet::Carnivore => rng.roll(1, 2, -1).max(1),
+                    _ => rng.roll(1, 2, 0),
+                } as usize;
+                AnimalEncounter {
+                    roll,
+                    behaviour,
+                    mass,
+                    quantity,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Display for Biosphere {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Biomass {} Complexity {} Compatibility {}",
+            self.biomass, self.complexity, self.compatibility
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> System {
+        let seed = Seed::new(String::from("test"));
+        generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc)
+    }
+
+    #[test]
+    fn test_vacuum_is_lifeless() {
+        let mut sys = setup();
+        sys.mainworld.atmosphere = 0;
+        let biosphere = Biosphere::new(&sys, &sys.mainworld);
+        assert!(!biosphere.has_life());
+        assert!(biosphere.encounter_table(Terrain::Plains).is_empty());
+    }
+
+    #[test]
+    fn test_garden_worlds_have_life() {
+        let mut sys = setup();
+        sys.mainworld.size = 7;
+        sys.mainworld.atmosphere = 6;
+        sys.mainworld.hydrographics = 6;
+        let biosphere = Biosphere::new(&sys, &sys.mainworld);
+        assert!(biosphere.biomass >= 4);
+        assert_eq!(biosphere, Biosphere::new(&sys, &sys.mainworld));
+    }
+
+    #[test]
+    fn test_settlement_leaves_life_alone() {
+        let sys = setup();
+        let mut settled = sys.mainworld.clone();
+        settled.population = (settled.population + 3) % 10;
+        settled.government = (settled.government + 5) % 14;
+        settled.law = (settled.law + 4) % 10;
+        settled.tech += 2;
+        settled.port = Starport::A;
+        assert_eq!(
+            Biosphere::new(&sys, &sys.mainworld),
+            Biosphere::new(&sys, &settled)
+        );
+    }
+
+    #[test]
+    fn test_hellworlds_are_hostile() {
+        let mut sys = setup();
+        sys.mainworld.size = 7;
+        sys.mainworld.atmosphere = 11;
+        sys.mainworld.hydrographics = 1;
+        let biosphere = Biosphere::new(&sys, &sys.mainworld);
+        assert!(biosphere.biomass <= 2);
+        assert_eq!(biosphere.compatibility, 0);
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::prelude::*;
+
+/// A hex corner on the lattice used by `Coordinate::vertices`.
+pub type Vertex = (i32, i32);
+
+/// One closed line around part of a polity's territory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BorderLoop {
+    pub vertices: Vec<Vertex>,
+    /// The hexes just inside the line, in the order the line passes them.
+    pub hexes: Vec<Coordinate>,
+    /// True when the loop surrounds an enclave or gap rather than the territory itself.
+    pub is_hole: bool,
+}
+
+impl BorderLoop {
+    /// Twice the area enclosed, positive for loops running clockwise on screen.
+    fn signed_area(vertices: &[Vertex]) -> i32 {
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Border {
+    pub allegiance: String,
+    pub loops: Vec<BorderLoop>,
+}
+
+impl Border {
+    pub fn outlines(&self) -> impl Iterator<Item = &BorderLoop> {
+        self.loops.iter().filter(|l| !l.is_hole)
+    }
+
+    pub fn holes(&self) -> impl Iterator<Item = &BorderLoop> {
+        self.loops.iter().filter(|l| l.is_hole)
+    }
+
+    /// Traveller Map `<Border>` elements, one per outline.
+    ///
+    /// Traveller Map fills every border it reads, so holes are left out of the
+    /// export rather than drawn as extra territory; the SVG map still cuts them out.
+    pub fn to_xml(&self) -> String {
+        self.outlines()
+            .map(|l| {
+                let path = l
+                    .hexes
+                    .iter()
+                    .map(|hex| hex.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                format!("<Border Allegiance=\"{}\">{path}</Border>", self.allegiance)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// Works out each allegiance's territory from the hexes its systems hold.
+///
+/// Empty hexes are claimed when at least two neighbouring systems share an
+/// allegiance and no rival allegiance borders them. Non-aligned systems and
+/// rival enclaves are left out, so they show up as holes.
+pub fn territories<'a, I, F>(claims: I, in_bounds: F) -> HashMap<String, HashSet<Coordinate>>
+where
+    I: IntoIterator<Item = (Coordinate, &'a str)>,
+    F: Fn(&Coordinate) -> bool,
+{
+    let occupied: HashMap<Coordinate, &str> = claims.into_iter().collect();
+    let mut territories: HashMap<String, HashSet<Coordinate>> = HashMap::new();
+
+    for (hex, allegiance) in &occupied {
+        if *allegiance != NON_ALIGNED {
+            territories
+                .entry(allegiance.to_string())
+                .or_default()
+                .insert(*hex);
+        }
+    }
+
+    let empty: HashSet<Coordinate> = occupied
+        .keys()
+        .flat_map(|hex| hex.neighbours())
+        .filter(|hex| !occupied.contains_key(hex) && in_bounds(hex))
+        .collect();
+
+    for hex in empty {
+        let neighbours: Vec<&str> = hex
+            .neighbours()
+            .iter()
+            .filter_map(|n| occupied.get(n).copied())
+            .filter(|a| *a != NON_ALIGNED)
+            .collect();
+        if let Some(first) = neighbours.first() {
+            if neighbours.len() >= 2 && neighbours.iter().all(|a| a == first) {
+                territories
+                    .entry(first.to_string())
+                    .or_default()
+                    .insert(hex);
+            }
+        }
+    }
+
+    territories
+}
+
+/// Traces the outline of a set of hexes into closed loops.
+pub fn trace_loops(territory: &HashSet<Coordinate>) -> Vec<BorderLoop> {
+    // Boundary edges keep the clockwise winding of the hex they belong to
+    let mut edges: HashMap<Vertex, (Vertex, Coordinate)> = HashMap::new();
+    for hex in territory {
+        let vertices = hex.vertices();
+        for neighbour in hex.neighbours() {
+            if territory.contains(&neighbour) {
+                continue;
+            }
+            let shared = neighbour.vertices();
+            for i in 0..6 {
+                let (from, to) = (vertices[i], vertices[(i + 1) % 6]);
+                if shared.contains(&from) && shared.contains(&to) {
+                    edges.insert(from, (to, *hex));
+                }
+            }
+        }
+    }
+
+    let mut starts: Vec<Vertex> = edges.keys().copied().collect();
+    starts.sort();
+
+    let mut loops = vec![];
+    for start in starts {
+        if !edges.contains_key(&start) {
+            continue;
+        }
+        let mut vertices = vec![];
+        let mut hexes: Vec<Coordinate> = vec![];
+        let mut current = start;
+        while let Some((next, hex)) = edges.remove(&current) {
+            vertices.push(current);
+            if hexes.last() != Some(&hex) {
+                hexes.push(hex);
+            }
+            current = next;
+        }
+        if hexes.len() > 1 && hexes.first() == hexes.last() {
+            hexes.pop();
+        }
+        let is_hole = BorderLoop::signed_area(&vertices) < 0;
+        loops.push(BorderLoop {
+            vertices,
+            hexes,
+            is_hole,
+        });
+    }
+    loops
+}
+
+/// Borders for every allegiance among the given hexes, sorted by allegiance.
+pub fn compute_borders<'a, I, F>(claims: I, in_bounds: F) -> Vec<Border>
+where
+    I: IntoIterator<Item = (Coordinate, &'a str)>,
+    F: Fn(&Coordinate) -> bool,
+{
+    let mut borders: Vec<Border> = territories(claims, in_bounds)
+        .into_iter()
+        .map(|(allegiance, territory)| Border {
+            allegiance,
+            loops: trace_loops(&territory),
+        })
+        .collect();
+    borders.sort_by(|a, b| a.allegiance.cmp(&b.allegiance));
+    borders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ring(centre: Coordinate) -> HashSet<Coordinate> {
+        centre.neighbours().into_iter().collect()
+    }
+
+    #[test]
+    fn test_single_hex() {
+        let hex = Coordinate::new(3, 3);
+        let loops = trace_loops(&HashSet::from([hex]));
+        assert_eq!(loops.len(), 1);
+        assert_eq!(loops[0].vertices.len(), 6);
+        assert_eq!(loops[0].hexes, vec![hex]);
+        assert!(!loops[0].is_hole);
+    }
+
+    #[test]
+    fn test_ring_has_hole() {
+        let loops = trace_loops(&ring(Coordinate::new(4, 4)));
+        assert_eq!(loops.len(), 2);
+        assert_eq!(loops.iter().filter(|l| l.is_hole).count(), 1);
+        assert_eq!(loops.iter().find(|l| l.is_hole).unwrap().vertices.len(), 6);
+    }
+
+    #[test]
+    fn test_enclave_is_excluded() {
+        let centre = Coordinate::new(4, 4);
+        let claims: Vec<(Coordinate, &str)> = ring(centre)
+            .into_iter()
+            .map(|hex| (hex, "ImAa"))
+            .chain([(centre, NON_ALIGNED)])
+            .collect();
+        let borders = compute_borders(claims, |_| true);
+        assert_eq!(borders.len(), 1);
+        assert_eq!(borders[0].holes().count(), 1);
+    }
+
+    #[test]
+    fn test_holes_are_not_exported() {
+        let claims: Vec<(Coordinate, &str)> = ring(Coordinate::new(4, 4))
+            .into_iter()
+            .map(|hex| (hex, "ImAa"))
+            .collect();
+        let borders = compute_borders(claims, |hex| *hex != Coordinate::new(4, 4));
+        assert_eq!(borders[0].holes().count(), 1);
+        assert_eq!(borders[0].to_xml().matches("<Border").count(), 1);
+    }
+
+    #[test]
+    fn test_gaps_are_filled() {
+        let claims = vec![
+            (Coordinate::new(4, 4), "ImAa"),
+            (Coordinate::new(4, 6), "ImAa"),
+        ];
+        let territory = territories(claims.clone(), |_| true)
+            .remove("ImAa")
+            .unwrap();
+        assert!(territory.contains(&Coordinate::new(4, 5)));
+
+        let territory = territories(claims, |hex| hex.col != 5)
+            .remove("ImAa")
+            .unwrap();
+        assert!(!territory.contains(&Coordinate::new(4, 5)));
+    }
+}
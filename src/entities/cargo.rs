@@ -0,0 +1,359 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// Fares in credits for one jump of 1 to 6 parsecs.
+const HIGH_FARES: [i64; 6] = [9_000, 14_000, 21_000, 34_000, 60_000, 210_000];
+const MIDDLE_FARES: [i64; 6] = [6_500, 10_000, 14_000, 23_000, 40_000, 130_000];
+const BASIC_FARES: [i64; 6] = [2_000, 3_000, 5_000, 8_000, 14_000, 55_000];
+const LOW_FARES: [i64; 6] = [700, 1_300, 2_200, 3_900, 7_200, 27_000];
+const FREIGHT_RATES: [i64; 6] = [1_000, 1_600, 2_600, 4_400, 8_500, 32_000];
+
+/// Credits paid for carrying one mail container.
+pub const MAIL_PAYMENT: i64 = 25_000;
+pub const MAIL_CONTAINER_TONS: i32 = 5;
+
+fn rate(table: &[i64; 6], distance: i32) -> i64 {
+    table[(distance.clamp(1, 6) - 1) as usize]
+}
+
+fn port_dm(port: Starport) -> i32 {
+    match port {
+        Starport::A => 2,
+        Starport::B => 1,
+        Starport::E => -1,
+        Starport::X => -3,
+        _ => 0,
+    }
+}
+
+fn zone_dm(zone: &TravelZone, amber: i32, red: i32) -> i32 {
+    match zone {
+        TravelZone::Green => 0,
+        TravelZone::Amber => amber,
+        TravelZone::Red => red,
+    }
+}
+
+/// How much passenger traffic a world generates at one end of a route.
+fn passenger_dm(world: &World) -> i32 {
+    let population = match world.population {
+        ..=1 => -4,
+        6..=7 => 1,
+        8.. => 3,
+        _ => 0,
+    };
+    let tech = match world.tech {
+        ..=6 => -1,
+        9.. => 1,
+        _ => 0,
+    };
+    population + port_dm(world.port) + tech + zone_dm(&world.travel_zone, 1, -4)
+}
+
+/// How much freight traffic a world generates at one end of a route.
+fn freight_dm(world: &World) -> i32 {
+    let population = match world.population {
+        ..=1 => -4,
+        6..=7 => 2,
+        8.. => 4,
+        _ => 0,
+    };
+    let tech = match world.tech {
+        ..=6 => -1,
+        9.. => 2,
+        _ => 0,
+    };
+    population + port_dm(world.port) + tech + zone_dm(&world.travel_zone, -2, -6)
+}
+
+/// Number of dice of passengers or lots for a modified traffic roll.
+fn traffic_dice(roll: i32) -> usize {
+    match roll {
+        ..=1 => 0,
+        2..=3 => 1,
+        4..=6 => 2,
+        7..=10 => 3,
+        11..=13 => 4,
+        14..=15 => 5,
+        16 => 6,
+        17 => 7,
+        18 => 8,
+        19 => 9,
+        _ => 10,
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Passengers {
+    pub high: i32,
+    pub middle: i32,
+    pub basic: i32,
+    pub low: i32,
+}
+
+impl Passengers {
+    pub fn total(&self) -> i32 {
+        self.high + self.middle + self.basic + self.low
+    }
+
+    /// Fares paid by every passenger for a jump of this length.
+    pub fn fares(&self, distance: i32) -> i64 {
+        self.high as i64 * rate(&HIGH_FARES, distance)
+            + self.middle as i64 * rate(&MIDDLE_FARES, distance)
+            + self.basic as i64 * rate(&BASIC_FARES, distance)
+            + self.low as i64 * rate(&LOW_FARES, distance)
+    }
+}
+
+impl Display for Passengers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "High {} Middle {} Basic {} Low {}",
+            self.high, self.middle, self.basic, self.low
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FreightKind {
+    Major,
+    Minor,
+    Incidental,
+}
+
+impl FreightKind {
+    pub const ALL: [FreightKind; 3] = [
+        FreightKind::Major,
+        FreightKind::Minor,
+        FreightKind::Incidental,
+    ];
+
+    fn traffic_dm(&self) -> i32 {
+        match self {
+            FreightKind::Major => -4,
+            FreightKind::Minor => 0,
+            FreightKind::Incidental => 2,
+        }
+    }
+
+    /// Tons per die rolled for a lot of this kind.
+    fn tons_per_die(&self) -> i32 {
+        match self {
+            FreightKind::Major => 10,
+            FreightKind::Minor => 5,
+            FreightKind::Incidental => 1,
+        }
+    }
+}
+
+impl Display for FreightKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FreightKind::Major => write!(f, "Major"),
+            FreightKind::Minor => write!(f, "Minor"),
+            FreightKind::Incidental => write!(f, "Incidental"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FreightLot {
+    pub kind: FreightKind,
+    pub tons: i32,
+}
+
+/// Everything waiting at a starport for a ship bound to a given destination.
+///
+/// Rolled from the departure system's seed, the destination and the date, so
+/// the same route on the same day always offers the same manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manifest {
+    pub date: Date,
+    pub distance: i32,
+    pub passengers: Passengers,
+    pub freight: Vec<FreightLot>,
+    /// Containers of mail, each of `MAIL_CONTAINER_TONS` tons.
+    pub mail: i32,
+}
+
+/// Seed for the cargo waiting on one date. Keyed on the destination's seed rather
+/// than its hex, so the same hex in another sector offers different cargo.
+fn manifest_seed(departure: &System, destination: &System, date: Date) -> Seed {
+    departure
+        .seed
+        .for_date(date)
+        .subseed(vec![&destination.seed])
+}
+
+impl Manifest {
+    pub fn new(departure: &System, destination: &System, distance: i32, date: Date) -> Self {
+        let mut rng = manifest_seed(departure, destination, date).to_rng();
+        let (from, to) = (&departure.mainworld, &destination.mainworld);
+        // Every parsec beyond the first puts people and shippers off
+        let range_dm = -(distance.max(1) - 1);
+
+        let passenger_roll = |rng: &mut Dice, dm: i32| {
+            let dice = traffic_dice(
+                rng.roll(2, 6, 0) + passenger_dm(from) + passenger_dm(to) + range_dm + dm,
+            );
+            rng.roll(dice, 6, 0)
+        };
+        let passengers = Passengers {
+            high: passenger_roll(&mut rng, -4),
+            middle: passenger_roll(&mut rng, 0),
+            basic: passenger_roll(&mut rng, 0),
+            low: passenger_roll(&mut rng, 1),
+        };
+
+        let freight_traffic = freight_dm(from) + freight_dm(to) + range_dm;
+        let freight = FreightKind::ALL
+            .iter()
+            .flat_map(|kind| {
+                let lots = traffic_dice(rng.roll(2, 6, 0) + freight_traffic + kind.traffic_dm());
+                (0..rng.roll(lots, 6, 0))
+                    .map(|_| FreightLot {
+                        kind: *kind,
+                        tons: rng.roll(1, 6, 0) * kind.tons_per_die(),
+                    })
+                    .collect::<Vec<FreightLot>>()
+            })
+            .collect();
+
+        let mail_dm = match freight_traffic {
+            ..=-10 => -2,
+            -9..=-5 => -1,
+            5..=9 => 1,
+            10.. => 2,
+            _ => 0,
+        } + if from.tech <= 5 { -4 } else { 0 };
+        let mail = if rng.roll(2, 6, mail_dm) >= 12 {
+            rng.roll(1, 6, 0)
+        } else {
+            0
+        };
+
+        Self {
+            date,
+            distance,
+            passengers,
+            freight,
+            mail,
+        }
+    }
+
+    pub fn freight_tons(&self) -> i32 {
+        self.freight.iter().map(|lot| lot.tons).sum()
+    }
+
+    /// Credits earned by carrying everything on the manifest.
+    pub fn revenue(&self) -> i64 {
+        self.passengers.fares(self.distance)
+            + self.freight_tons() as i64 * rate(&FREIGHT_RATES, self.distance)
+            + self.mail as i64 * MAIL_PAYMENT
+    }
+}
+
+impl Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let freight = FreightKind::ALL
+            .iter()
+            .map(|kind| {
+                let lots = self
+                    .freight
+                    .iter()
+                    .filter(|lot| lot.kind == *kind)
+                    .map(|lot| lot.tons.to_string())
+                    .collect::<Vec<String>>();
+                format!("{kind} [{}]", lots.join(", "))
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        write!(
+            f,
+            "{} Jump-{}\nPassengers: {}\nFreight: {}\nMail: {} containers\nRevenue: Cr{}",
+            self.date,
+            self.distance,
+            self.passengers,
+            freight,
+            self.mail,
+            self.revenue()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> (System, System) {
+        let seed = Seed::new(String::from("test"));
+        (
+            generate_system(
+                seed.subseed(vec![1]),
+                Coordinate::new(1, 1),
+                StellarPopulation::Disc,
+            ),
+            generate_system(
+                seed.subseed(vec![2]),
+                Coordinate::new(1, 2),
+                StellarPopulation::Disc,
+            ),
+        )
+    }
+
+    #[test]
+    fn test_same_hex_in_another_sector() {
+        let (from, to) = setup();
+        let mut elsewhere = to.clone();
+        elsewhere.seed = to.seed.subseed(vec!["another sector"]);
+        let date = Date::new(1105, 42);
+        assert_ne!(
+            manifest_seed(&from, &to, date),
+            manifest_seed(&from, &elsewhere, date)
+        );
+        assert_eq!(
+            Manifest::new(&from, &to, 1, date),
+            Manifest::new(&from, &to.clone(), 1, date)
+        );
+    }
+
+    #[test]
+    fn test_tech_draws_passengers() {
+        let (mut from, _) = setup();
+        from.mainworld.tech = 5;
+        let primitive = passenger_dm(&from.mainworld);
+        from.mainworld.tech = 12;
+        assert_eq!(passenger_dm(&from.mainworld), primitive + 2);
+    }
+
+    #[test]
+    fn test_manifest_is_reproducible() {
+        let (from, to) = setup();
+        let date = Date::new(1105, 42);
+        assert_eq!(
+            Manifest::new(&from, &to, 1, date),
+            Manifest::new(&from, &to, 1, date)
+        );
+    }
+
+    #[test]
+    fn test_traffic_dice() {
+        assert_eq!(traffic_dice(-3), 0);
+        assert_eq!(traffic_dice(8), 3);
+        assert_eq!(traffic_dice(25), 10);
+    }
+
+    #[test]
+    fn test_fares_grow_with_distance() {
+        let passengers = Passengers {
+            high: 1,
+            middle: 1,
+            basic: 1,
+            low: 1,
+        };
+        assert!(passengers.fares(1) < passengers.fares(2));
+        assert_eq!(passengers.fares(9), passengers.fares(6));
+    }
+}
@@ -1,4 +1,7 @@
-#[derive(Debug, Copy, Clone, std::hash::Hash)]
+use crate::prelude::*;
+
+/// A hex position printed as XXYY, so `row` is the map column and `col` the map row.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, std::hash::Hash)]
 pub struct Coordinate {
     pub row: i32,
     pub col: i32,
@@ -8,6 +11,61 @@ impl Coordinate {
     pub fn new(row: i32, col: i32) -> Self {
         Self { row, col }
     }
+
+    /// The same hex counted from hex 0000 of sector 0000 rather than its own sector.
+    pub fn global(&self, sector: Coordinate) -> Coordinate {
+        Coordinate::new(
+            sector.row * SECTOR_ROWS + self.row,
+            sector.col * SECTOR_COLS + self.col,
+        )
+    }
+
+    /// Letter of the subsector holding this hex, A to P.
+    pub fn subsector(&self) -> char {
+        let across = (self.row - 1).rem_euclid(SECTOR_ROWS) / SUBSECTOR_ROWS;
+        let down = (self.col - 1).rem_euclid(SECTOR_COLS) / SUBSECTOR_COLS;
+        (b'A' + (down * 4 + across) as u8) as char
+    }
+
+    /// Cube coordinates for a hex map where even map columns, the XX of XXYY, sit half a hex lower.
+    fn cube(&self) -> (i32, i32, i32) {
+        let x = self.row;
+        let z = self.col - (self.row + self.row.rem_euclid(2)).div_euclid(2);
+        (x, -x - z, z)
+    }
+
+    pub fn neighbours(&self) -> [Coordinate; 6] {
+        let down = 1 - self.row.rem_euclid(2);
+        [
+            Coordinate::new(self.row, self.col - 1),
+            Coordinate::new(self.row, self.col + 1),
+            Coordinate::new(self.row - 1, self.col - 1 + down),
+            Coordinate::new(self.row - 1, self.col + down),
+            Coordinate::new(self.row + 1, self.col - 1 + down),
+            Coordinate::new(self.row + 1, self.col + down),
+        ]
+    }
+
+    /// Corners of the hex on a lattice of half hex widths by half hex heights, clockwise from the east.
+    pub fn vertices(&self) -> [(i32, i32); 6] {
+        let x = 3 * self.row;
+        let y = 2 * self.col + 1 - self.row.rem_euclid(2);
+        [
+            (x + 2, y),
+            (x + 1, y + 1),
+            (x - 1, y + 1),
+            (x - 2, y),
+            (x - 1, y - 1),
+            (x + 1, y - 1),
+        ]
+    }
+
+    /// Distance in parsecs between two hexes of the same sector.
+    pub fn distance(&self, other: &Coordinate) -> i32 {
+        let (x1, y1, z1) = self.cube();
+        let (x2, y2, z2) = other.cube();
+        (x1 - x2).abs().max((y1 - y2).abs()).max((z1 - z2).abs())
+    }
 }
 
 impl std::fmt::Display for Coordinate {
@@ -15,3 +73,96 @@ impl std::fmt::Display for Coordinate {
         write!(f, "{:0>2}{:0>2}", self.row, self.col)
     }
 }
+
+impl std::str::FromStr for Coordinate {
+    type Err = String;
+
+    /// Parses a four digit XXYY hex.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 4 || !s.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Expected a hex like 0101, got '{s}'"));
+        }
+        let (row, col) = s.split_at(2);
+        Ok(Coordinate::new(row.parse().unwrap(), col.parse().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3240".parse(), Ok(Coordinate::new(32, 40)));
+        assert!("32-40".parse::<Coordinate>().is_err());
+    }
+
+    #[test]
+    fn test_distance_to_self() {
+        let hex = Coordinate::new(5, 5);
+        assert_eq!(hex.distance(&hex), 0)
+    }
+
+    #[test]
+    fn test_distance_to_neighbours() {
+        // Odd column: neighbours lean upwards
+        let odd = Coordinate::new(5, 5);
+        for (row, col) in [(5, 4), (5, 6), (4, 4), (4, 5), (6, 4), (6, 5)] {
+            assert_eq!(odd.distance(&Coordinate::new(row, col)), 1)
+        }
+        // Even column: neighbours lean downwards
+        let even = Coordinate::new(6, 5);
+        for (row, col) in [(6, 4), (6, 6), (5, 5), (5, 6), (7, 5), (7, 6)] {
+            assert_eq!(even.distance(&Coordinate::new(row, col)), 1)
+        }
+    }
+
+    #[test]
+    fn test_neighbours_match_distance() {
+        for hex in [Coordinate::new(5, 5), Coordinate::new(6, 5)] {
+            for neighbour in hex.neighbours() {
+                assert_eq!(hex.distance(&neighbour), 1)
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbours_share_an_edge() {
+        let hex = Coordinate::new(6, 5);
+        for neighbour in hex.neighbours() {
+            let shared = hex
+                .vertices()
+                .into_iter()
+                .filter(|v| neighbour.vertices().contains(v))
+                .count();
+            assert_eq!(shared, 2)
+        }
+    }
+
+    #[test]
+    fn test_subsectors() {
+        assert_eq!(Coordinate::new(1, 1).subsector(), 'A');
+        assert_eq!(Coordinate::new(8, 10).subsector(), 'A');
+        assert_eq!(Coordinate::new(9, 1).subsector(), 'B');
+        assert_eq!(Coordinate::new(1, 11).subsector(), 'E');
+        assert_eq!(Coordinate::new(32, 40).subsector(), 'P');
+    }
+
+    #[test]
+    fn test_distance_follows_printed_hex() {
+        let hex = |s: &str| s.parse::<Coordinate>().unwrap();
+        // Stepping along XX zigzags across the map, so every column over is a single jump
+        assert_eq!(hex("0101").distance(&hex("0201")), 1);
+        assert_eq!(hex("0102").distance(&hex("0201")), 1);
+        assert_eq!(hex("0101").distance(&hex("0202")), 2);
+        assert_eq!(hex("0101").distance(&hex("0801")), 7);
+        // Even columns sit lower, so 0202 touches 0103 but 0201 does not touch 0103
+        assert_eq!(hex("0202").distance(&hex("0103")), 1);
+        assert_eq!(hex("0201").distance(&hex("0103")), 2);
+    }
+
+    #[test]
+    fn test_distance_along_column() {
+        assert_eq!(Coordinate::new(1, 1).distance(&Coordinate::new(1, 9)), 8)
+    }
+}
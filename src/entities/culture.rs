@@ -0,0 +1,282 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// How a world treats offworlders, from its acceptance.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Xenophilia {
+    Xenophobic,
+    Wary,
+    Tolerant,
+    Welcoming,
+    Xenophilic,
+}
+
+impl Xenophilia {
+    pub fn from_acceptance(acceptance: i32) -> Self {
+        match acceptance {
+            ..=2 => Xenophilia::Xenophobic,
+            3..=4 => Xenophilia::Wary,
+            5..=7 => Xenophilia::Tolerant,
+            8..=10 => Xenophilia::Welcoming,
+            _ => Xenophilia::Xenophilic,
+        }
+    }
+}
+
+impl Display for Xenophilia {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Xenophilia::Xenophobic => write!(f, "Xenophobic"),
+            Xenophilia::Wary => write!(f, "Wary"),
+            Xenophilia::Tolerant => write!(f, "Tolerant"),
+            Xenophilia::Welcoming => write!(f, "Welcoming"),
+            Xenophilia::Xenophilic => write!(f, "Xenophilic"),
+        }
+    }
+}
+
+const VALUES: [&str; 36] = [
+    "honour",
+    "family",
+    "tradition",
+    "progress",
+    "wealth",
+    "learning",
+    "piety",
+    "duty",
+    "freedom",
+    "order",
+    "beauty",
+    "strength",
+    "hospitality",
+    "thrift",
+    "craftsmanship",
+    "loyalty",
+    "equality",
+    "ancestry",
+    "frankness",
+    "modesty",
+    "ambition",
+    "patience",
+    "courage",
+    "harmony with nature",
+    "competition",
+    "consensus",
+    "self-reliance",
+    "charity",
+    "secrecy",
+    "rank",
+    "cleanliness",
+    "artistry",
+    "endurance",
+    "wit",
+    "restraint",
+    "pleasure",
+];
+
+const QUIRKS: [&str; 36] = [
+    "names are never spoken aloud in public",
+    "meals are eaten in strict silence",
+    "everyone wears a mask outside the home",
+    "children are raised communally",
+    "debts are inherited for seven generations",
+    "colours of clothing show a person's profession",
+    "sleep is taken in short shifts through the day and night",
+    "disputes are settled by public debate",
+    "pets are regarded as full family members",
+    "the dead are kept in the home",
+    "offworld food is considered unclean",
+    "all agreements are sung rather than signed",
+    "body modification marks every rite of passage",
+    "personal wealth is displayed as jewellery",
+    "left-handedness is a mark of good fortune",
+    "time is measured from the founding of the colony",
+    "strangers are assigned a local sponsor",
+    "public displays of emotion are taboo",
+    "gifts must always be refused twice",
+    "elders speak first in every conversation",
+    "everyone carries a ceremonial blade",
+    "titles replace personal names entirely",
+    "architecture avoids right angles",
+    "fasting marks every holiday",
+    "a person's age is never asked",
+    "music plays constantly in public spaces",
+    "bare feet are required indoors",
+    "marriages are arranged by lottery",
+    "hair is shaved as a sign of mourning",
+    "laughter in public is considered rude",
+    "every home keeps a shrine to the founders",
+    "written records are distrusted",
+    "visitors must be ritually cleansed",
+    "numbers have strong luck associations",
+    "professions are hereditary",
+    "the weather is discussed before any business",
+];
+
+const GREETINGS: [&str; 12] = [
+    "a nod",
+    "a firm handshake",
+    "a bow, deeper for superiors",
+    "clasping forearms",
+    "touching foreheads",
+    "an exchange of small gifts",
+    "reciting lineage",
+    "a formal spoken blessing",
+    "a raised open palm",
+    "a kiss on both cheeks",
+    "a ritual exchange of questions about health",
+    "a sequence of hand signs",
+];
+
+fn heterogeneity_descriptor(heterogeneity: i32) -> &'static str {
+    match heterogeneity {
+        ..=2 => "monolithic",
+        3..=5 => "harmonious",
+        6..=8 => "diverse",
+        9..=11 => "discordant",
+        _ => "fragmented",
+    }
+}
+
+fn strangeness_descriptor(strangeness: i32) -> &'static str {
+    match strangeness {
+        ..=1 => "familiar",
+        2..=4 => "distinctive",
+        5..=7 => "unusual",
+        8..=10 => "alien",
+        _ => "incomprehensible",
+    }
+}
+
+fn symbols_descriptor(symbols: i32) -> &'static str {
+    match symbols {
+        ..=3 => "plain and literal",
+        4..=8 => "practical",
+        9..=12 => "rich in symbolism",
+        _ => "steeped in esoteric symbolism",
+    }
+}
+
+/// A world's culture, read off the Cx extension and fleshed out from tables.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Culture {
+    pub xenophilia: Xenophilia,
+    pub heterogeneity: &'static str,
+    pub strangeness: &'static str,
+    pub symbols: &'static str,
+    pub values: Vec<&'static str>,
+    pub quirks: Vec<&'static str>,
+    pub greeting: &'static str,
+}
+
+impl Culture {
+    /// The culture of the mainworld's inhabitants, or `None` if it has none.
+    pub fn new(sys: &System) -> Option<Self> {
+        if sys.mainworld.population == 0 {
+            return None;
+        }
+        let cx = &sys.extensions;
+        let mut rng = sys.seed.subseed(vec!["culture"]).to_rng();
+        let mut draw = |table: &[&'static str], count: i32| {
+            let mut picked: Vec<&'static str> = vec![];
+            while (picked.len() as i32) < count.min(table.len() as i32) {
+                let entry = table[rng.roll(1, table.len() as i32, -1) as usize];
+                if !picked.contains(&entry) {
+                    picked.push(entry)
+                }
+            }
+            picked
+        };
+
+        // Diverse worlds hold more competing values, strange ones more quirks
+        let values = draw(&VALUES, 1 + (cx.heterogeneity / 5).clamp(0, 2));
+        let quirks = draw(&QUIRKS, (cx.strangeness / 3).clamp(0, 4));
+        // More symbolic cultures have more elaborate greetings
+        let greeting = {
+            let offset = (cx.symbols / 2).clamp(0, GREETINGS.len() as i32 - 6);
+            draw(&GREETINGS[offset as usize..offset as usize + 6], 1)[0]
+        };
+
+        Some(Self {
+            xenophilia: Xenophilia::from_acceptance(cx.acceptance),
+            heterogeneity: heterogeneity_descriptor(cx.heterogeneity),
+            strangeness: strangeness_descriptor(cx.strangeness),
+            symbols: symbols_descriptor(cx.symbols),
+            values,
+            quirks,
+            greeting,
+        })
+    }
+
+    /// A short readable account of the culture.
+    pub fn narrative(&self) -> String {
+        let values = match self.values.split_last() {
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+            None => String::from("little in particular"),
+        };
+        let quirks = match self.quirks.is_empty() {
+            true => String::new(),
+            false => format!(
+                " Visitors soon notice that {}.",
+                self.quirks.join("; that ")
+            ),
+        };
+        format!(
+            "A {} society, {} towards offworlders, that prizes {values}. Its customs seem {} to outsiders and its art and language are {}. People greet one another with {}.{quirks}",
+            self.heterogeneity,
+            self.xenophilia.to_string().to_lowercase(),
+            self.strangeness,
+            self.symbols,
+            self.greeting,
+        )
+    }
+}
+
+impl Display for Culture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.narrative())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> System {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.mainworld.population = 6;
+        sys
+    }
+
+    #[test]
+    fn test_culture_is_stable() {
+        let sys = setup();
+        assert_eq!(Culture::new(&sys), Culture::new(&sys));
+        assert!(!Culture::new(&sys).unwrap().values.is_empty());
+    }
+
+    #[test]
+    fn test_uninhabited_worlds_have_no_culture() {
+        let mut sys = setup();
+        sys.mainworld.population = 0;
+        assert_eq!(Culture::new(&sys), None);
+    }
+
+    #[test]
+    fn test_acceptance() {
+        let mut sys = setup();
+        sys.extensions.acceptance = 1;
+        assert_eq!(
+            Culture::new(&sys).unwrap().xenophilia,
+            Xenophilia::Xenophobic
+        );
+        sys.extensions.acceptance = 12;
+        assert_eq!(
+            Culture::new(&sys).unwrap().xenophilia,
+            Xenophilia::Xenophilic
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const DAYS_PER_YEAR: i32 = 365;
+
+/// A day in the Imperial calendar, written as day-year (e.g. 001-1105).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: i32,
+    /// Day of the year, from 1 to 365.
+    pub day: i32,
+}
+
+impl Date {
+    pub fn new(year: i32, day: i32) -> Self {
+        Self {
+            year,
+            day: day.clamp(1, DAYS_PER_YEAR),
+        }
+    }
+
+    /// Days since day 001 of year 0.
+    pub fn ordinal(&self) -> i64 {
+        self.year as i64 * DAYS_PER_YEAR as i64 + (self.day - 1) as i64
+    }
+
+    pub fn from_ordinal(ordinal: i64) -> Self {
+        Self {
+            year: ordinal.div_euclid(DAYS_PER_YEAR as i64) as i32,
+            day: ordinal.rem_euclid(DAYS_PER_YEAR as i64) as i32 + 1,
+        }
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_ordinal(self.ordinal() + days)
+    }
+
+    /// Weeks since day 001 of year 0, for things that change weekly.
+    pub fn week(&self) -> i64 {
+        self.ordinal().div_euclid(7)
+    }
+}
+
+/// How a calendar writes its dates.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum DateFormat {
+    /// 001-1105
+    #[default]
+    DayYear,
+    /// 1105-001
+    YearDay,
+    /// Day 1, 1105
+    Long,
+}
+
+impl Display for DateFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateFormat::DayYear => write!(f, "day-year"),
+            DateFormat::YearDay => write!(f, "year-day"),
+            DateFormat::Long => write!(f, "long"),
+        }
+    }
+}
+
+impl FromStr for DateFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "day-year" => Ok(DateFormat::DayYear),
+            "year-day" => Ok(DateFormat::YearDay),
+            "long" => Ok(DateFormat::Long),
+            _ => Err(format!("Unknown date format '{s}'")),
+        }
+    }
+}
+
+/// A campaign's way of numbering years and writing dates.
+///
+/// Dates are always stored in Imperial years; the calendar only changes how
+/// they are read and written.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Calendar {
+    /// Imperial year that is year 0 of this calendar.
+    pub epoch: i32,
+    /// Written after the year, e.g. "AD" or "Imperial".
+    pub era: String,
+    pub format: DateFormat,
+}
+
+impl Calendar {
+    pub fn new(epoch: i32, era: &str, format: DateFormat) -> Self {
+        Self {
+            epoch,
+            era: String::from(era),
+            format,
+        }
+    }
+
+    /// The year as this calendar counts it.
+    pub fn year(&self, date: Date) -> i32 {
+        date.year - self.epoch
+    }
+
+    pub fn format(&self, date: Date) -> String {
+        let year = self.year(date);
+        let written = match self.format {
+            DateFormat::DayYear => format!("{:03}-{year}", date.day),
+            DateFormat::YearDay => format!("{year}-{:03}", date.day),
+            DateFormat::Long => format!("Day {}, {year}", date.day),
+        };
+        match self.era.as_str() {
+            "" => written,
+            era => format!("{written} {era}"),
+        }
+    }
+
+    pub fn parse(&self, s: &str) -> Result<Date, String> {
+        let s = s.trim();
+        let s = s
+            .strip_suffix(self.era.as_str())
+            .filter(|_| !self.era.is_empty())
+            .unwrap_or(s)
+            .trim();
+        // Days are never negative, so the separator is the first dash after a
+        // day and the last dash before one; years may carry a minus sign
+        let (day, year) = match self.format {
+            DateFormat::DayYear => s.split_once('-'),
+            DateFormat::YearDay => s.rsplit_once('-').map(|(year, day)| (day, year)),
+            DateFormat::Long => s
+                .strip_prefix("Day ")
+                .and_then(|rest| rest.split_once(", ")),
+        }
+        .ok_or_else(|| {
+            format!(
+                "Expected a date like {}, got '{s}'",
+                self.format(Date::new(self.epoch + 1105, 1))
+            )
+        })?;
+        let day: i32 = day.parse().map_err(|_| format!("Bad day in '{s}'"))?;
+        let year: i32 = year.parse().map_err(|_| format!("Bad year in '{s}'"))?;
+        if !(1..=DAYS_PER_YEAR).contains(&day) {
+            return Err(format!("Day out of range in '{s}'"));
+        }
+        Ok(Date::new(year + self.epoch, day))
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:03}-{}", self.day, self.year)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (day, year) = s
+            .split_once('-')
+            .ok_or_else(|| format!("Expected a date like 001-1105, got '{s}'"))?;
+        let day: i32 = day.parse().map_err(|_| format!("Bad day in '{s}'"))?;
+        let year: i32 = year.parse().map_err(|_| format!("Bad year in '{s}'"))?;
+        if !(1..=DAYS_PER_YEAR).contains(&day) {
+            return Err(format!("Day out of range in '{s}'"));
+        }
+        Ok(Self { year, day })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let date = Date::new(1105, 7);
+        assert_eq!(date.to_string(), "007-1105");
+        assert_eq!("007-1105".parse::<Date>(), Ok(date));
+        assert!("400-1105".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn test_calendar_epoch() {
+        let calendar = Calendar::new(-1000, "AD", DateFormat::Long);
+        let date = Date::new(1105, 12);
+        assert_eq!(calendar.format(date), "Day 12, 2105 AD");
+        assert_eq!(calendar.parse("Day 12, 2105 AD"), Ok(date));
+
+        let calendar = Calendar::new(0, "", DateFormat::YearDay);
+        assert_eq!(calendar.format(date), "1105-012");
+        assert_eq!(calendar.parse("1105-012"), Ok(date));
+    }
+
+    #[test]
+    fn test_negative_years() {
+        let date = Date::new(-50, 200);
+        assert_eq!(date.to_string(), "200--50");
+        assert_eq!("200--50".parse::<Date>(), Ok(date));
+
+        let calendar = Calendar::new(0, "", DateFormat::YearDay);
+        assert_eq!(calendar.parse("-1105-200"), Ok(Date::new(-1105, 200)));
+        assert_eq!(calendar.format(Date::new(-1105, 200)), "-1105-200");
+        let calendar = Calendar::new(0, "", DateFormat::DayYear);
+        assert_eq!(calendar.parse("200--1105"), Ok(Date::new(-1105, 200)));
+        assert!(calendar.parse("-200-1105").is_err());
+    }
+
+    #[test]
+    fn test_add_days_wraps_year() {
+        assert_eq!(Date::new(1105, 365).add_days(1), Date::new(1106, 1));
+        assert_eq!(Date::new(1105, 1).add_days(-1), Date::new(1104, 365));
+    }
+}
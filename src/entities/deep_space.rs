@@ -0,0 +1,122 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// Objects found in hexes without a star system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeepSpaceFeature {
+    /// A planet with no star, frozen solid if it holds any water.
+    RoguePlanet {
+        size: i32,
+        icy: bool,
+    },
+    BrownDwarf,
+    Nebula,
+    /// Loose cometary bodies, whose ice can be refined into fuel.
+    CometCloud,
+    Station {
+        allegiance: String,
+    },
+    RefuellingDepot {
+        allegiance: String,
+    },
+}
+
+impl DeepSpaceFeature {
+    /// Fuel a ship can take on here.
+    pub fn fuel(&self) -> FuelQuality {
+        match self {
+            DeepSpaceFeature::RefuellingDepot { .. } => FuelQuality::Refined,
+            DeepSpaceFeature::CometCloud => FuelQuality::Unrefined,
+            DeepSpaceFeature::RoguePlanet { icy: true, .. } => FuelQuality::Unrefined,
+            _ => FuelQuality::None,
+        }
+    }
+}
+
+impl Display for DeepSpaceFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeepSpaceFeature::RoguePlanet { size, icy: true } => {
+                write!(f, "Icy rogue planet (size {size})")
+            }
+            DeepSpaceFeature::RoguePlanet { size, icy: false } => {
+                write!(f, "Rogue planet (size {size})")
+            }
+            DeepSpaceFeature::BrownDwarf => write!(f, "Brown dwarf"),
+            DeepSpaceFeature::Nebula => write!(f, "Nebula"),
+            DeepSpaceFeature::CometCloud => write!(f, "Comet cloud"),
+            DeepSpaceFeature::Station { allegiance } => {
+                write!(f, "Deep space station ({allegiance})")
+            }
+            DeepSpaceFeature::RefuellingDepot { allegiance } => {
+                write!(f, "Refuelling depot ({allegiance})")
+            }
+        }
+    }
+}
+
+/// Something of interest in a hex with no star system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeepSpace {
+    pub location: Coordinate,
+    pub features: Vec<DeepSpaceFeature>,
+    pub ancient_site: Option<AncientSite>,
+}
+
+impl DeepSpace {
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty() && self.ancient_site.is_none()
+    }
+
+    /// The best fuel on offer in the hex.
+    pub fn fuel(&self) -> FuelQuality {
+        self.features
+            .iter()
+            .map(|feature| feature.fuel())
+            .min()
+            .unwrap_or(FuelQuality::None)
+    }
+
+    /// Whether a ship can refuel here and jump on.
+    pub fn has_fuel(&self) -> bool {
+        self.fuel() != FuelQuality::None
+    }
+}
+
+impl Display for DeepSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.location,
+            self.features
+                .iter()
+                .map(|feature| feature.to_string())
+                .chain(self.ancient_site.iter().map(|site| site.to_string()))
+                .collect::<Vec<String>>()
+                .join("; ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_fuel() {
+        let hex = DeepSpace {
+            location: Coordinate::new(1, 1),
+            features: vec![
+                DeepSpaceFeature::CometCloud,
+                DeepSpaceFeature::RefuellingDepot {
+                    allegiance: String::from(NON_ALIGNED),
+                },
+            ],
+            ancient_site: None,
+        };
+        assert_eq!(hex.fuel(), FuelQuality::Refined);
+        assert!(hex.has_fuel());
+    }
+}
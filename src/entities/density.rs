@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use crate::prelude::*;
+
+/// How likely a hex is to contain a star system.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Density {
+    Rift,
+    Sparse,
+    #[default]
+    Standard,
+    Dense,
+    Cluster,
+}
+
+impl Density {
+    pub const ALL: [Density; 5] = [
+        Density::Rift,
+        Density::Sparse,
+        Density::Standard,
+        Density::Dense,
+        Density::Cluster,
+    ];
+
+    pub fn system_present<R: Rollable>(&self, rng: &mut R) -> bool {
+        match self {
+            Density::Rift => rng.roll(2, 6, 0) == 12,
+            Density::Sparse => rng.roll(1, 6, 0) > 5,
+            Density::Standard => rng.roll(1, 6, 0) > 4,
+            Density::Dense => rng.roll(1, 6, 0) > 3,
+            Density::Cluster => rng.roll(1, 6, 0) > 2,
+        }
+    }
+
+    pub fn thinner(&self) -> Self {
+        match self {
+            Density::Rift | Density::Sparse => Density::Rift,
+            Density::Standard => Density::Sparse,
+            Density::Dense => Density::Standard,
+            Density::Cluster => Density::Dense,
+        }
+    }
+
+    pub fn denser(&self) -> Self {
+        match self {
+            Density::Rift => Density::Sparse,
+            Density::Sparse => Density::Standard,
+            Density::Standard => Density::Dense,
+            Density::Dense | Density::Cluster => Density::Cluster,
+        }
+    }
+}
+
+impl Display for Density {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Density::Rift => "rift",
+            Density::Sparse => "sparse",
+            Density::Standard => "standard",
+            Density::Dense => "dense",
+            Density::Cluster => "cluster",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Density {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Density::ALL
+            .into_iter()
+            .find(|d| d.to_string() == s.to_lowercase())
+            .ok_or_else(|| format!("Unknown density '{s}'"))
+    }
+}
+
+/// Decides the density of every hex in a galaxy.
+pub trait DensityMap: Debug {
+    fn density(&self, sector: Coordinate, hex: Coordinate) -> Density;
+
+    fn population(&self, _sector: Coordinate, _hex: Coordinate) -> StellarPopulation {
+        StellarPopulation::Disc
+    }
+}
+
+impl DensityMap for Density {
+    fn density(&self, _sector: Coordinate, _hex: Coordinate) -> Density {
+        *self
+    }
+}
+
+/// A base density with hand placed overrides for whole sectors or single hexes.
+#[derive(Debug, Clone, Default)]
+pub struct DensityOverrides {
+    pub base: Density,
+    pub sectors: HashMap<Coordinate, Density>,
+    pub hexes: HashMap<(Coordinate, Coordinate), Density>,
+}
+
+impl DensityOverrides {
+    pub fn new(base: Density) -> Self {
+        Self {
+            base,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_sector(mut self, sector: Coordinate, density: Density) -> Self {
+        self.sectors.insert(sector, density);
+        self
+    }
+
+    pub fn with_hex(mut self, sector: Coordinate, hex: Coordinate, density: Density) -> Self {
+        self.hexes.insert((sector, hex), density);
+        self
+    }
+}
+
+impl DensityMap for DensityOverrides {
+    fn density(&self, sector: Coordinate, hex: Coordinate) -> Density {
+        self.hexes
+            .get(&(sector, hex))
+            .or_else(|| self.sectors.get(&sector))
+            .copied()
+            .unwrap_or(self.base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn present_count(density: Density) -> usize {
+        let mut rng = Seed::new(String::from("test")).to_rng();
+        (0..1000)
+            .filter(|_| density.system_present(&mut rng))
+            .count()
+    }
+
+    #[test]
+    fn test_densities_are_ordered() {
+        let counts: Vec<usize> = Density::ALL.into_iter().map(present_count).collect();
+        assert!(counts.windows(2).all(|w| w[0] < w[1]))
+    }
+
+    #[test]
+    fn test_parse_density() {
+        for density in Density::ALL {
+            assert_eq!(density.to_string().parse::<Density>(), Ok(density))
+        }
+        assert!("void".parse::<Density>().is_err())
+    }
+
+    #[test]
+    fn test_overrides() {
+        let rift = Coordinate::new(1, 0);
+        let hex = Coordinate::new(4, 4);
+        let map = DensityOverrides::new(Density::Sparse)
+            .with_sector(rift, Density::Rift)
+            .with_hex(rift, hex, Density::Cluster);
+
+        assert_eq!(map.density(Coordinate::new(0, 0), hex), Density::Sparse);
+        assert_eq!(map.density(rift, Coordinate::new(1, 1)), Density::Rift);
+        assert_eq!(map.density(rift, hex), Density::Cluster);
+    }
+}
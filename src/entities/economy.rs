@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// Total inhabitants from the population code and the population digit of the PBG.
+pub fn total_population(world: &World) -> i64 {
+    match world.population {
+        0 => 0,
+        pop => world.population_digit.max(1) as i64 * 10_i64.pow(pop.clamp(0, 15) as u32),
+    }
+}
+
+/// Yearly income per inhabitant, in credits.
+///
+/// T5 measures a world's output in the resource units of its economic extension,
+/// each worth a credit a year to every inhabitant.
+pub fn per_capita_income(sys: &System) -> i64 {
+    match sys.mainworld.population {
+        0 => 0,
+        _ => sys.extensions.resource_units() as i64,
+    }
+}
+
+/// Gross world product, in megacredits: resource units times population.
+pub fn gross_world_product(sys: &System) -> i64 {
+    (total_population(&sys.mainworld) as i128 * per_capita_income(sys) as i128 / 1_000_000) as i64
+}
+
+/// Combined population and product of a group of systems.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Economy {
+    pub population: i64,
+    /// Gross product, in megacredits.
+    pub gwp: i64,
+    pub resource_units: i64,
+}
+
+impl Economy {
+    pub fn new<'a, I>(systems: I) -> Self
+    where
+        I: IntoIterator<Item = &'a System>,
+    {
+        systems.into_iter().fold(Self::default(), |acc, sys| Self {
+            population: acc.population + total_population(&sys.mainworld),
+            gwp: acc.gwp + gross_world_product(sys),
+            resource_units: acc.resource_units
+                + match sys.mainworld.population {
+                    0 => 0,
+                    _ => sys.extensions.resource_units() as i64,
+                },
+        })
+    }
+
+    /// Average yearly income per inhabitant, in credits.
+    pub fn per_capita(&self) -> i64 {
+        match self.population {
+            0 => 0,
+            pop => (self.gwp as i128 * 1_000_000 / pop as i128) as i64,
+        }
+    }
+}
+
+impl Display for Economy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pop {:>19} GWP {:>15} MCr Per capita {:>7} Cr RU {:>8}",
+            self.population,
+            self.gwp,
+            self.per_capita(),
+            self.resource_units
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> System {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.mainworld.population = 6;
+        sys.mainworld.population_digit = 3;
+        sys
+    }
+
+    #[test]
+    fn test_total_population() {
+        let sys = setup();
+        assert_eq!(total_population(&sys.mainworld), 3_000_000);
+    }
+
+    #[test]
+    fn test_empty_world_has_no_economy() {
+        let mut sys = setup();
+        sys.mainworld.population = 0;
+        assert_eq!(gross_world_product(&sys), 0);
+        assert_eq!(Economy::new([&sys]), Economy::default());
+    }
+
+    #[test]
+    fn test_product_follows_resource_units() {
+        let sys = setup();
+        let resource_units = sys.extensions.resource_units() as i64;
+        assert_eq!(per_capita_income(&sys), resource_units);
+        assert_eq!(gross_world_product(&sys), 3 * resource_units);
+    }
+
+    #[test]
+    fn test_economies_add_up() {
+        let sys = setup();
+        let one = Economy::new([&sys]);
+        let two = Economy::new([&sys, &sys]);
+        assert_eq!(two.population, one.population * 2);
+        assert_eq!(two.gwp, one.gwp * 2);
+        assert_eq!(two.per_capita(), one.per_capita());
+    }
+}
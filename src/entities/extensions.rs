@@ -21,7 +21,7 @@ impl Display for Extensions {
 
         let rli = [self.resources, self.labor, self.infrastructure]
             .into_iter()
-            .map(|x| to_ehex(x))
+            .map(to_ehex)
             .collect::<Vec<String>>()
             .join("");
 
@@ -38,7 +38,7 @@ impl Display for Extensions {
             self.symbols,
         ]
         .into_iter()
-        .map(|x| to_ehex(x))
+        .map(to_ehex)
         .collect::<Vec<String>>()
         .join("");
 
@@ -53,7 +53,7 @@ impl Display for Extensions {
 impl Extensions {
     pub fn new(rng: &mut Dice, world: &World, orbital_resources: i32) -> Self {
         // Extensions
-        let importance = importance_extension(&world);
+        let importance = importance_extension(world);
         // Economic
         let (resources, labor, infrastructure, efficiency) =
             economic_extension(rng, importance, world, orbital_resources);
@@ -74,9 +74,27 @@ impl Extensions {
         }
     }
 
+    /// Recomputes importance after the world gains bases, along with the values it feeds into.
+    pub fn refresh_importance(&mut self, world: &World) {
+        let importance = importance_extension(world);
+        let delta = importance - self.importance;
+        if delta == 0 {
+            return;
+        }
+        self.importance = importance;
+        self.infrastructure = match world.population {
+            0 => 0,
+            1..=3 => importance.max(0),
+            _ => (self.infrastructure + delta).max(0),
+        };
+        if world.population > 0 {
+            self.acceptance = (world.population + importance).max(1);
+        }
+    }
+
     pub fn expected_daily_ship_traffic(&self) -> i32 {
         match self.importance {
-            5 => 100,
+            5.. => 100,
             4 => 15,
             3 => 6,
             2 => 4,
@@ -105,14 +123,15 @@ impl Extensions {
 
 fn importance_extension(world: &World) -> i32 {
     [
-        ["A", "B"].contains(&world.port.as_str()),
+        [Starport::A, Starport::B].contains(&world.port),
         world.tech > 15,
         world.tech > 9,
         world.is_agricultural(),
         world.is_rich(),
         world.is_high_pop(),
         world.is_industrial(),
-        world.has_naval_base() && world.has_scout_base(),
+        // Non-Imperial navies call their naval bases military bases
+        (world.has_naval_base() || world.has_military_base()) && world.has_scout_base(),
         world.has_way_station(),
     ]
     .into_iter()
@@ -120,7 +139,7 @@ fn importance_extension(world: &World) -> i32 {
         - [
             world.tech < 9,
             world.population < 7,
-            ["D", "E", "X"].contains(&world.port.as_str()),
+            [Starport::D, Starport::E, Starport::X].contains(&world.port),
         ]
         .into_iter()
         .fold(0, |acc, x| acc + x as i32)
@@ -4,23 +4,77 @@ use crate::prelude::*;
 #[derive(Debug)]
 pub struct Galaxy {
     seed: Seed,
+    density: Box<dyn DensityMap>,
+    /// Campaign wide nobility rules, overriding each polity's customs.
+    nobility: Option<NobilityRules>,
+    calendar: Calendar,
 }
 
 impl Galaxy {
     pub fn new(seed: String) -> Self {
         Self {
             seed: Seed::new(seed),
+            density: Box::new(Density::Standard),
+            nobility: None,
+            calendar: Calendar::default(),
         }
     }
 
     pub fn random() -> Self {
         Self {
             seed: Seed::random(),
+            density: Box::new(Density::Standard),
+            nobility: None,
+            calendar: Calendar::default(),
         }
     }
 
+    pub fn with_density<D: DensityMap + 'static>(mut self, density: D) -> Self {
+        self.density = Box::new(density);
+        self
+    }
+
+    pub fn with_nobility(mut self, rules: NobilityRules) -> Self {
+        self.nobility = Some(rules);
+        self
+    }
+
+    pub fn with_calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Seed for galaxy wide events on a given day, such as news.
+    pub fn date_seed(&self, date: Date) -> Seed {
+        self.seed.for_date(date)
+    }
+
+    /// Replaces the density map with the galaxy's own seeded core, arms and voids.
+    pub fn with_structure(self) -> Self {
+        let structure = GalacticStructure::new(self.seed.subseed(vec!["structure"]));
+        self.with_density(structure)
+    }
+
+    pub fn density(&self, sec_coordinate: Coordinate, hex_coordinate: Coordinate) -> Density {
+        self.density.density(sec_coordinate, hex_coordinate)
+    }
+
     pub fn get_sector(&self, coordinate: Coordinate) -> Sector {
-        generate_sector(self.seed.subseed(vec![coordinate]), 32, 40)
+        let sector = generate_sector(
+            self.seed.subseed(vec![coordinate]),
+            coordinate,
+            SECTOR_ROWS as u32,
+            SECTOR_COLS as u32,
+            self.density.as_ref(),
+        );
+        match self.nobility {
+            Some(rules) => sector.with_nobility(rules),
+            None => sector,
+        }
     }
 
     pub fn get_system(&self, sec_coordinate: Coordinate, hex_coordinate: Coordinate) -> System {
@@ -29,6 +83,7 @@ impl Galaxy {
             .subseed(vec![sec_coordinate])
             .subseed(vec![hex_coordinate]);
 
-        generate_system(seed, hex_coordinate)
+        let population = self.density.population(sec_coordinate, hex_coordinate);
+        generate_system(seed, hex_coordinate, population)
     }
 }
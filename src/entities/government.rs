@@ -0,0 +1,367 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GovernmentType {
+    None,
+    Company,
+    ParticipatingDemocracy,
+    SelfPerpetuatingOligarchy,
+    RepresentativeDemocracy,
+    FeudalTechnocracy,
+    CaptiveGovernment,
+    Balkanisation,
+    CivilServiceBureaucracy,
+    ImpersonalBureaucracy,
+    CharismaticDictator,
+    NonCharismaticLeader,
+    CharismaticOligarchy,
+    ReligiousDictatorship,
+    ReligiousAutocracy,
+    TotalitarianOligarchy,
+}
+
+impl GovernmentType {
+    pub const ALL: [GovernmentType; 16] = [
+        GovernmentType::None,
+        GovernmentType::Company,
+        GovernmentType::ParticipatingDemocracy,
+        GovernmentType::SelfPerpetuatingOligarchy,
+        GovernmentType::RepresentativeDemocracy,
+        GovernmentType::FeudalTechnocracy,
+        GovernmentType::CaptiveGovernment,
+        GovernmentType::Balkanisation,
+        GovernmentType::CivilServiceBureaucracy,
+        GovernmentType::ImpersonalBureaucracy,
+        GovernmentType::CharismaticDictator,
+        GovernmentType::NonCharismaticLeader,
+        GovernmentType::CharismaticOligarchy,
+        GovernmentType::ReligiousDictatorship,
+        GovernmentType::ReligiousAutocracy,
+        GovernmentType::TotalitarianOligarchy,
+    ];
+
+    /// The government for a UWP digit, with anything beyond F treated as F.
+    pub fn from_code(code: i32) -> Self {
+        Self::ALL[code.clamp(0, 15) as usize]
+    }
+
+    pub fn code(&self) -> i32 {
+        Self::ALL.iter().position(|g| g == self).unwrap_or(0) as i32
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            GovernmentType::None => "No government structure; family bonds predominate",
+            GovernmentType::Company => {
+                "Ruling functions are assumed by a company managerial elite"
+            }
+            GovernmentType::ParticipatingDemocracy => {
+                "Ruling functions are reached by the advice and consent of the citizenry directly"
+            }
+            GovernmentType::SelfPerpetuatingOligarchy => {
+                "Ruling functions are performed by a restricted minority, with little or no input from the mass of citizenry"
+            }
+            GovernmentType::RepresentativeDemocracy => {
+                "Ruling functions are performed by elected representatives"
+            }
+            GovernmentType::FeudalTechnocracy => {
+                "Ruling functions are performed by specific individuals for persons who agree to be ruled by them"
+            }
+            GovernmentType::CaptiveGovernment => {
+                "Ruling functions are performed by an imposed leadership answerable to an outside group"
+            }
+            GovernmentType::Balkanisation => {
+                "No central authority exists; rival governments compete for control"
+            }
+            GovernmentType::CivilServiceBureaucracy => {
+                "Ruling functions are performed by government agencies employing individuals selected for their expertise"
+            }
+            GovernmentType::ImpersonalBureaucracy => {
+                "Ruling functions are performed by agencies insulated from the governed citizens"
+            }
+            GovernmentType::CharismaticDictator => {
+                "Ruling functions are performed by agencies directed by a single leader who enjoys the overwhelming confidence of the citizens"
+            }
+            GovernmentType::NonCharismaticLeader => {
+                "A previous charismatic dictator has been replaced by a leader through normal channels"
+            }
+            GovernmentType::CharismaticOligarchy => {
+                "Ruling functions are performed by a select group of members of an organisation or class"
+            }
+            GovernmentType::ReligiousDictatorship => {
+                "Ruling functions are performed by a religious organisation without regard to the specific needs of the citizenry"
+            }
+            GovernmentType::ReligiousAutocracy => {
+                "Government by a single religious leader having absolute power over the citizenry"
+            }
+            GovernmentType::TotalitarianOligarchy => {
+                "Government by an all-powerful minority which maintains absolute control through widespread coercion and oppression"
+            }
+        }
+    }
+}
+
+impl Display for GovernmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GovernmentType::None => "None",
+            GovernmentType::Company => "Company/Corporation",
+            GovernmentType::ParticipatingDemocracy => "Participating Democracy",
+            GovernmentType::SelfPerpetuatingOligarchy => "Self-Perpetuating Oligarchy",
+            GovernmentType::RepresentativeDemocracy => "Representative Democracy",
+            GovernmentType::FeudalTechnocracy => "Feudal Technocracy",
+            GovernmentType::CaptiveGovernment => "Captive Government",
+            GovernmentType::Balkanisation => "Balkanisation",
+            GovernmentType::CivilServiceBureaucracy => "Civil Service Bureaucracy",
+            GovernmentType::ImpersonalBureaucracy => "Impersonal Bureaucracy",
+            GovernmentType::CharismaticDictator => "Charismatic Dictator",
+            GovernmentType::NonCharismaticLeader => "Non-Charismatic Leader",
+            GovernmentType::CharismaticOligarchy => "Charismatic Oligarchy",
+            GovernmentType::ReligiousDictatorship => "Religious Dictatorship",
+            GovernmentType::ReligiousAutocracy => "Religious Autocracy",
+            GovernmentType::TotalitarianOligarchy => "Totalitarian Oligarchy",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FactionStrength {
+    Obscure,
+    Fringe,
+    Minor,
+    Notable,
+    Significant,
+    Overwhelming,
+}
+
+impl FactionStrength {
+    fn from_roll(roll: i32) -> Self {
+        match roll {
+            ..=3 => FactionStrength::Obscure,
+            4..=5 => FactionStrength::Fringe,
+            6..=7 => FactionStrength::Minor,
+            8..=9 => FactionStrength::Notable,
+            10..=11 => FactionStrength::Significant,
+            _ => FactionStrength::Overwhelming,
+        }
+    }
+}
+
+impl Display for FactionStrength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactionStrength::Obscure => write!(f, "Obscure"),
+            FactionStrength::Fringe => write!(f, "Fringe"),
+            FactionStrength::Minor => write!(f, "Minor"),
+            FactionStrength::Notable => write!(f, "Notable"),
+            FactionStrength::Significant => write!(f, "Significant"),
+            FactionStrength::Overwhelming => write!(f, "Overwhelming"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Agenda {
+    Reform,
+    Tradition,
+    Secession,
+    Expansion,
+    Isolation,
+    Faith,
+    Commerce,
+    Militarism,
+    Technology,
+    Xenophobia,
+    Openness,
+}
+
+impl Display for Agenda {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Agenda::Reform => write!(f, "Reform"),
+            Agenda::Tradition => write!(f, "Tradition"),
+            Agenda::Secession => write!(f, "Secession"),
+            Agenda::Expansion => write!(f, "Expansion"),
+            Agenda::Isolation => write!(f, "Isolation"),
+            Agenda::Faith => write!(f, "Faith"),
+            Agenda::Commerce => write!(f, "Commerce"),
+            Agenda::Militarism => write!(f, "Militarism"),
+            Agenda::Technology => write!(f, "Technology"),
+            Agenda::Xenophobia => write!(f, "Xenophobia"),
+            Agenda::Openness => write!(f, "Openness"),
+        }
+    }
+}
+
+/// Where a faction stands with the ruling government.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Relationship {
+    /// Fighting the government, or on a balkanised world, a rival government.
+    Insurgent,
+    Dissident,
+    Opposition,
+    Loyal,
+}
+
+impl Display for Relationship {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Relationship::Insurgent => write!(f, "Insurgent"),
+            Relationship::Dissident => write!(f, "Dissident"),
+            Relationship::Opposition => write!(f, "Opposition"),
+            Relationship::Loyal => write!(f, "Loyal"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Faction {
+    /// The government the faction would set up if it took power.
+    pub kind: GovernmentType,
+    pub strength: FactionStrength,
+    pub agenda: Agenda,
+    pub relationship: Relationship,
+}
+
+impl Display for Faction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} ({}, {})",
+            self.strength, self.kind, self.agenda, self.relationship
+        )
+    }
+}
+
+/// A world's government and the factions competing with it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Government {
+    pub kind: GovernmentType,
+    pub factions: Vec<Faction>,
+}
+
+impl Government {
+    /// Rolls factions as in the standard procedure: 1D3 of them, more on
+    /// lawless or balkanised worlds and fewer under dictators, adjusted by
+    /// how heterogeneous the culture is.
+    pub fn new(sys: &System) -> Self {
+        let world = &sys.mainworld;
+        let ruling = GovernmentType::from_code(world.government);
+        if world.population == 0 {
+            return Self {
+                kind: ruling,
+                factions: vec![],
+            };
+        }
+
+        let mut rng = sys.seed.subseed(vec!["government"]).to_rng();
+        let cx = &sys.extensions;
+        let government_dm = match world.government {
+            0 | 7 => 1,
+            10.. => -1,
+            _ => 0,
+        };
+        let heterogeneity_dm = (cx.heterogeneity - 5) / 3;
+        let count = (rng.roll(1, 3, 0) + government_dm + heterogeneity_dm).max(1);
+
+        let factions = (0..count)
+            .map(|_| {
+                let kind = GovernmentType::from_code(rng.roll(2, 6, world.population - 7).max(0));
+                let strength = FactionStrength::from_roll(rng.roll(2, 6, 0));
+                let agenda = Self::agenda(&mut rng, kind, cx);
+                // Accepting cultures tolerate opposition; closed ones drive it underground
+                let relationship = if world.government == 7 {
+                    Relationship::Insurgent
+                } else if kind == ruling {
+                    Relationship::Loyal
+                } else {
+                    match rng.roll(2, 6, (cx.acceptance - 5) / 2) {
+                        ..=3 => Relationship::Insurgent,
+                        4..=6 => Relationship::Dissident,
+                        7..=10 => Relationship::Opposition,
+                        _ => Relationship::Loyal,
+                    }
+                };
+                Faction {
+                    kind,
+                    strength,
+                    agenda,
+                    relationship,
+                }
+            })
+            .collect();
+
+        Self {
+            kind: ruling,
+            factions,
+        }
+    }
+
+    fn agenda(rng: &mut Dice, kind: GovernmentType, cx: &Extensions) -> Agenda {
+        match kind {
+            GovernmentType::ReligiousDictatorship | GovernmentType::ReligiousAutocracy => {
+                return Agenda::Faith
+            }
+            GovernmentType::Company => return Agenda::Commerce,
+            GovernmentType::FeudalTechnocracy => return Agenda::Technology,
+            _ => (),
+        }
+        match rng.roll(2, 6, 0) {
+            2 => Agenda::Secession,
+            3 if cx.acceptance <= 3 => Agenda::Xenophobia,
+            3 => Agenda::Isolation,
+            4 => Agenda::Militarism,
+            5 => Agenda::Expansion,
+            6 | 7 => Agenda::Reform,
+            8 if cx.strangeness >= 7 => Agenda::Faith,
+            8 => Agenda::Tradition,
+            9 => Agenda::Commerce,
+            10 => Agenda::Technology,
+            11 if cx.acceptance >= 8 => Agenda::Openness,
+            11 => Agenda::Tradition,
+            _ => Agenda::Isolation,
+        }
+    }
+}
+
+impl Display for Government {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.kind.description())?;
+        for faction in &self.factions {
+            write!(f, "\n  {faction}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_government_codes() {
+        for (code, kind) in GovernmentType::ALL.iter().enumerate() {
+            assert_eq!(GovernmentType::from_code(code as i32), *kind);
+            assert_eq!(kind.code(), code as i32);
+        }
+        assert_eq!(
+            GovernmentType::from_code(20),
+            GovernmentType::TotalitarianOligarchy
+        );
+    }
+
+    #[test]
+    fn test_factions() {
+        let seed = Seed::new(String::from("test"));
+        let sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        let government = Government::new(&sys);
+        assert_eq!(government, Government::new(&sys));
+        assert_eq!(government.kind.code(), sys.mainworld.government.min(15));
+        if sys.mainworld.population > 0 {
+            assert!(!government.factions.is_empty());
+        }
+    }
+}
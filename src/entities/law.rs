@@ -0,0 +1,389 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LawCategory {
+    Weapons,
+    Drugs,
+    Information,
+    Technology,
+    Travellers,
+    Psionics,
+}
+
+impl LawCategory {
+    pub const ALL: [LawCategory; 6] = [
+        LawCategory::Weapons,
+        LawCategory::Drugs,
+        LawCategory::Information,
+        LawCategory::Technology,
+        LawCategory::Travellers,
+        LawCategory::Psionics,
+    ];
+
+    /// What each law level from 1 to 9 adds to the list of restrictions.
+    pub fn restrictions(&self) -> [&'static str; 9] {
+        match self {
+            LawCategory::Weapons => [
+                "Poison gas, explosives, undetectable weapons, weapons of mass destruction",
+                "Portable energy and laser weapons",
+                "Heavy weapons",
+                "Light assault weapons and submachine guns",
+                "Personal concealable weapons",
+                "All firearms except shotguns and stunners",
+                "Shotguns",
+                "All bladed weapons and stunners",
+                "Any weapons",
+            ],
+            LawCategory::Drugs => [
+                "Highly addictive and dangerous narcotics",
+                "Highly addictive narcotics",
+                "Combat drugs",
+                "Addictive narcotics",
+                "Anagathics",
+                "Fast and slow drugs",
+                "All narcotics",
+                "Medicinal drugs",
+                "All drugs",
+            ],
+            LawCategory::Information => [
+                "Intellect programs",
+                "Agent programs",
+                "Intrusion programs",
+                "Security programs",
+                "Expert programs",
+                "Recent news from offworld",
+                "Library programs",
+                "Information technology",
+                "Any data from offworld",
+            ],
+            LawCategory::Technology => [
+                "Dangerous technologies such as nanotechnology",
+                "Alien technology",
+                "TL15 items",
+                "TL13 items",
+                "TL11 items",
+                "TL9 items",
+                "TL7 items",
+                "TL5 items",
+                "TL3 items",
+            ],
+            LawCategory::Travellers => [
+                "Visitors must contact planetary authorities on arrival",
+                "Visitors must report their manifest",
+                "Landing only at authorised sites",
+                "Visitors must register all business",
+                "Excessive contact with citizens forbidden",
+                "Visitors confined to the starport",
+                "Citizens may not leave the planet",
+                "Visitors must be escorted at all times",
+                "No offworlders permitted",
+            ],
+            LawCategory::Psionics => [
+                "Dangerous talents must be registered",
+                "All psionic powers must be registered",
+                "Telepathy restricted to government approved users",
+                "Teleportation and clairvoyance restricted",
+                "All psionics restricted to government psionicists",
+                "Psionic drugs banned",
+                "Use of psionics forbidden",
+                "Psionic technology banned",
+                "All psionics banned",
+            ],
+        }
+    }
+
+    /// Item keywords banned at each law level, with any exceptions the restriction names.
+    fn items(&self) -> [(&'static [&'static str], &'static [&'static str]); 9] {
+        match self {
+            LawCategory::Weapons => [
+                (&["poison", "explosive", "grenade", "bomb", "nuclear"], &[]),
+                (&["laser", "energy"], &[]),
+                (&["heavy", "plasma", "fusion", "launcher", "artillery"], &[]),
+                (&["assault", "submachine", "smg", "autorifle"], &[]),
+                (&["concealable", "holdout", "derringer"], &[]),
+                (
+                    &["firearm", "gun", "pistol", "revolver", "rifle", "carbine"],
+                    &["shotgun", "stunner", "stun"],
+                ),
+                (&["shotgun"], &[]),
+                (
+                    &[
+                        "blade", "bladed", "knife", "sword", "dagger", "cutlass", "stunner", "stun",
+                    ],
+                    &[],
+                ),
+                (&["weapon"], &[]),
+            ],
+            LawCategory::Drugs => [
+                (&[], &[]),
+                (&[], &[]),
+                (&["combat"], &[]),
+                (&[], &[]),
+                (&["anagathic"], &[]),
+                (&["fast", "slow"], &[]),
+                (&["narcotic"], &[]),
+                (&["medicinal", "medicine"], &[]),
+                (&["drug"], &[]),
+            ],
+            LawCategory::Information => [
+                (&["intellect"], &[]),
+                (&["agent"], &[]),
+                (&["intrusion"], &[]),
+                (&["security"], &[]),
+                (&["expert"], &[]),
+                (&["news"], &[]),
+                (&["library"], &[]),
+                (&["computer"], &[]),
+                (&["data"], &[]),
+            ],
+            LawCategory::Technology => [
+                (&["nanotechnology", "nanite"], &[]),
+                (&["alien"], &[]),
+                (&["tl15"], &[]),
+                (&["tl13"], &[]),
+                (&["tl11"], &[]),
+                (&["tl9"], &[]),
+                (&["tl7"], &[]),
+                (&["tl5"], &[]),
+                (&["tl3"], &[]),
+            ],
+            LawCategory::Travellers => [(&[], &[]); 9],
+            LawCategory::Psionics => [
+                (&[], &[]),
+                (&[], &[]),
+                (&["telepathy"], &[]),
+                (&["teleportation", "clairvoyance"], &[]),
+                (&[], &[]),
+                (&["psi"], &[]),
+                (&[], &[]),
+                (&["psitech"], &[]),
+                (&["psionic"], &[]),
+            ],
+        }
+    }
+
+    /// How strongly a government leans on this category compared to its overall law level.
+    fn government_dm(&self, government: i32) -> i32 {
+        match (self, government) {
+            (LawCategory::Weapons, 0) => -1,
+            (LawCategory::Weapons, 6 | 15) => 1,
+            (LawCategory::Drugs, 13 | 14) => 2,
+            (LawCategory::Information, 2 | 4) => -1,
+            (LawCategory::Information, 9 | 15) => 2,
+            (LawCategory::Technology, 5) => -2,
+            (LawCategory::Technology, 13 | 14) => 1,
+            (LawCategory::Travellers, 6) => 2,
+            (LawCategory::Travellers, 3 | 15) => 1,
+            (LawCategory::Psionics, 2 | 4) => -1,
+            (LawCategory::Psionics, 13 | 14) => 2,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for LawCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LawCategory::Weapons => write!(f, "Weapons"),
+            LawCategory::Drugs => write!(f, "Drugs"),
+            LawCategory::Information => write!(f, "Information"),
+            LawCategory::Technology => write!(f, "Technology"),
+            LawCategory::Travellers => write!(f, "Travellers"),
+            LawCategory::Psionics => write!(f, "Psionics"),
+        }
+    }
+}
+
+impl FromStr for LawCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LawCategory::ALL
+            .into_iter()
+            .find(|c| c.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unknown law category '{s}'"))
+    }
+}
+
+/// A world's law level broken down into what is restricted and how hard it is enforced.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LawProfile {
+    pub law: i32,
+    pub government: i32,
+}
+
+impl LawProfile {
+    pub fn new(world: &World) -> Self {
+        Self {
+            law: world.law,
+            government: world.government,
+        }
+    }
+
+    /// Effective law level for one category.
+    pub fn level(&self, category: LawCategory) -> i32 {
+        match self.law {
+            0 => 0,
+            law => (law + category.government_dm(self.government)).max(1),
+        }
+    }
+
+    /// Everything restricted in a category, mildest first.
+    pub fn restrictions(&self, category: LawCategory) -> Vec<&'static str> {
+        let level = self.level(category).clamp(0, 9) as usize;
+        category.restrictions()[..level].to_vec()
+    }
+
+    /// Whether something first banned at `ban_level` in `category` is allowed here.
+    pub fn is_legal(&self, category: LawCategory, ban_level: i32) -> bool {
+        self.level(category) < ban_level
+    }
+
+    /// Looks up an item by name, e.g. "laser carbine", and says whether it is legal here.
+    ///
+    /// Each word is matched against the item keywords of every restriction, mildest first,
+    /// skipping restrictions that explicitly exempt the item. Returns `None` when nothing matches.
+    pub fn is_legal_item(&self, item: &str) -> Option<bool> {
+        let item = item.to_lowercase();
+        let words = item
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<&str>>();
+        let mentions = |keywords: &[&str]| {
+            words.iter().any(|word| {
+                keywords
+                    .iter()
+                    .any(|keyword| *word == *keyword || word.strip_suffix('s') == Some(keyword))
+            })
+        };
+        LawCategory::ALL
+            .iter()
+            .flat_map(|category| {
+                category
+                    .items()
+                    .into_iter()
+                    .zip(1..)
+                    .map(move |(items, level)| (*category, items, level))
+            })
+            .find(|(_, (keywords, exceptions), _)| mentions(keywords) && !mentions(exceptions))
+            .map(|(category, _, level)| self.is_legal(category, level))
+    }
+
+    /// Modifier to the customs roll from how zealously the government enforces its laws.
+    pub fn enforcement_dm(&self) -> i32 {
+        match self.government {
+            0 => -2,
+            2 | 7 => -1,
+            3 | 8 | 10..=12 => 1,
+            6 | 9 | 13 | 14 => 2,
+            15 => 3,
+            _ => 0,
+        }
+    }
+
+    /// Travellers are checked when 2D minus the enforcement DM rolls the law level or under.
+    pub fn customs_check(&self, rng: &mut Dice) -> bool {
+        self.law > 0 && rng.roll(2, 6, -self.enforcement_dm()) <= self.law
+    }
+
+    /// Chance from 0 to 1 of being checked by customs on arrival.
+    pub fn customs_odds(&self) -> f64 {
+        if self.law == 0 {
+            return 0.0;
+        }
+        let passing = (1..=6)
+            .flat_map(|a| (1..=6).map(move |b| a + b))
+            .filter(|roll| roll - self.enforcement_dm() <= self.law)
+            .count();
+        passing as f64 / 36.0
+    }
+}
+
+impl Display for LawProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Law {} (customs {:.0}%)",
+            self.law,
+            self.customs_odds() * 100.0
+        )?;
+        for category in LawCategory::ALL {
+            let restrictions = self.restrictions(category);
+            let restrictions = match restrictions.is_empty() {
+                true => String::from("No restrictions"),
+                false => restrictions.join("; "),
+            };
+            write!(
+                f,
+                "\n{:11} {:2} {}",
+                category.to_string(),
+                self.level(category),
+                restrictions
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_laser_carbine() {
+        let lax = LawProfile {
+            law: 1,
+            government: 4,
+        };
+        let strict = LawProfile {
+            law: 2,
+            government: 4,
+        };
+        assert_eq!(lax.is_legal_item("laser carbine"), Some(true));
+        assert_eq!(strict.is_legal_item("laser carbine"), Some(false));
+        assert_eq!(strict.is_legal_item("sandwich"), None);
+    }
+
+    #[test]
+    fn test_weapon_exceptions() {
+        let at = |law| LawProfile { law, government: 4 };
+        assert_eq!(at(5).is_legal_item("shotgun"), Some(true));
+        assert_eq!(at(6).is_legal_item("shotgun"), Some(true));
+        assert_eq!(at(7).is_legal_item("shotgun"), Some(false));
+        assert_eq!(at(5).is_legal_item("stunner"), Some(true));
+        assert_eq!(at(6).is_legal_item("stunner"), Some(true));
+        assert_eq!(at(7).is_legal_item("stunner"), Some(true));
+        assert_eq!(at(8).is_legal_item("stunner"), Some(false));
+        assert_eq!(at(6).is_legal_item("stun gun"), Some(true));
+        assert_eq!(at(6).is_legal_item("autopistol"), None);
+        assert_eq!(at(6).is_legal_item("pistol"), Some(false));
+    }
+
+    #[test]
+    fn test_government_shifts_categories() {
+        let theocracy = LawProfile {
+            law: 4,
+            government: 13,
+        };
+        assert_eq!(theocracy.level(LawCategory::Weapons), 4);
+        assert_eq!(theocracy.level(LawCategory::Psionics), 6);
+        assert_eq!(theocracy.restrictions(LawCategory::Weapons).len(), 4);
+    }
+
+    #[test]
+    fn test_customs_odds() {
+        let lawless = LawProfile {
+            law: 0,
+            government: 15,
+        };
+        let police_state = LawProfile {
+            law: 12,
+            government: 15,
+        };
+        assert_eq!(lawless.customs_odds(), 0.0);
+        assert_eq!(police_state.customs_odds(), 1.0);
+    }
+}
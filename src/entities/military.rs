@@ -0,0 +1,197 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// Share of the economy a government spends on defence, in tenths.
+pub fn militarism(government: i32) -> i32 {
+    match government {
+        0 => 1,
+        1 | 2 | 4 | 8 => 3,
+        9 => 4,
+        3 | 5 | 11 | 12 | 14 => 5,
+        6 | 7 | 10 | 13 => 7,
+        _ => 8,
+    }
+}
+
+/// The forces defending a single system.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Military {
+    /// Strength of planetary defences from 0 (none) to 15 (a fortress world).
+    pub planetary_defence: i32,
+    /// Ground troops, in battalions.
+    pub garrison: i32,
+    /// Starship squadrons of the polity's navy based here.
+    pub naval_squadrons: i32,
+    /// Local non-jump defence boats.
+    pub system_squadrons: i32,
+    /// Jump range of the squadrons based here.
+    pub jump: i32,
+    /// Yearly defence spending, in resource units.
+    pub budget: i32,
+}
+
+impl Military {
+    pub fn new(sys: &System) -> Self {
+        let world = &sys.mainworld;
+        if world.population == 0 {
+            return Self {
+                planetary_defence: 0,
+                garrison: 0,
+                naval_squadrons: 0,
+                system_squadrons: 0,
+                jump: 0,
+                budget: 0,
+            };
+        }
+
+        let militarism = militarism(world.government);
+        let budget = sys.extensions.resource_units() * militarism / 10;
+
+        let planetary_defence =
+            ((world.population + world.tech / 2) * militarism / 10 + world.law / 4).clamp(0, 15);
+
+        let base_troops = [
+            (world.has_naval_base() || world.has_military_base()) as i32 * 2,
+            world.has_depot() as i32 * 4,
+            world.has_scout_base() as i32,
+        ]
+        .into_iter()
+        .sum::<i32>();
+        let garrison = (world.population - 2).max(0) * militarism / 2 + base_troops;
+
+        let jump = jump_for_tech(world.tech);
+        let naval_squadrons = if jump == 0 {
+            0
+        } else {
+            [
+                (world.has_naval_base() || world.has_military_base()) as i32 * (1 + world.tech / 4),
+                world.has_depot() as i32 * 2,
+            ]
+            .into_iter()
+            .sum()
+        };
+        let system_squadrons = if world.tech < 7 {
+            0
+        } else {
+            world.population * militarism / 10
+        };
+
+        Self {
+            planetary_defence,
+            garrison,
+            naval_squadrons,
+            system_squadrons,
+            jump,
+            budget,
+        }
+    }
+
+    pub fn is_undefended(&self) -> bool {
+        self.planetary_defence == 0
+            && self.garrison == 0
+            && self.naval_squadrons == 0
+            && self.system_squadrons == 0
+    }
+}
+
+impl Display for Military {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_undefended() {
+            return write!(f, "Undefended");
+        }
+        write!(
+            f,
+            "Defences {}, {} battalions, {} naval squadrons (J{}), {} system squadrons, budget {} RU",
+            self.planetary_defence,
+            self.garrison,
+            self.naval_squadrons,
+            self.jump,
+            self.system_squadrons,
+            self.budget
+        )
+    }
+}
+
+/// The combined forces and defence budget of every member of a polity.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PolityForces {
+    pub allegiance: String,
+    pub garrison: i32,
+    pub naval_squadrons: i32,
+    pub system_squadrons: i32,
+    /// Member contributions to the polity's fleet, in resource units.
+    pub fleet_budget: i32,
+}
+
+impl PolityForces {
+    pub fn new<'a, I>(allegiance: &str, members: I) -> Self
+    where
+        I: IntoIterator<Item = &'a System>,
+    {
+        members.into_iter().map(Military::new).fold(
+            Self {
+                allegiance: String::from(allegiance),
+                ..Default::default()
+            },
+            |acc, m| Self {
+                garrison: acc.garrison + m.garrison,
+                naval_squadrons: acc.naval_squadrons + m.naval_squadrons,
+                system_squadrons: acc.system_squadrons + m.system_squadrons,
+                fleet_budget: acc.fleet_budget + m.budget,
+                ..acc
+            },
+        )
+    }
+}
+
+impl Display for PolityForces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:4} {} battalions, {} naval squadrons, {} system squadrons, fleet budget {} RU",
+            self.allegiance,
+            self.garrison,
+            self.naval_squadrons,
+            self.system_squadrons,
+            self.fleet_budget
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> System {
+        let seed = Seed::new(String::from("test"));
+        generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc)
+    }
+
+    #[test]
+    fn test_empty_world_is_undefended() {
+        let mut sys = setup();
+        sys.mainworld.population = 0;
+        assert!(Military::new(&sys).is_undefended());
+    }
+
+    #[test]
+    fn test_naval_base_adds_squadrons() {
+        let mut sys = setup();
+        sys.mainworld.population = 7;
+        sys.mainworld.tech = 12;
+        sys.mainworld.bases = vec![];
+        let without = Military::new(&sys);
+        sys.mainworld.bases = vec![Base::Naval];
+        let with = Military::new(&sys);
+        assert!(with.naval_squadrons > without.naval_squadrons);
+        assert!(with.garrison > without.garrison);
+    }
+
+    #[test]
+    fn test_polity_totals() {
+        let sys = setup();
+        let forces = PolityForces::new("ImAa", [&sys, &sys]);
+        assert_eq!(forces.fleet_budget, Military::new(&sys).budget * 2);
+    }
+}
@@ -1,13 +1,53 @@
+pub mod ancient;
+pub use ancient::*;
+pub mod biosphere;
+pub use biosphere::*;
+pub mod border;
+pub use border::*;
+pub mod cargo;
+pub use cargo::*;
 pub mod coordinate;
 pub use coordinate::*;
+pub mod culture;
+pub use culture::*;
+pub mod date;
+pub use date::*;
+pub mod deep_space;
+pub use deep_space::*;
+pub mod density;
+pub use density::*;
+pub mod economy;
+pub use economy::*;
 pub mod extensions;
 pub use extensions::*;
+pub mod government;
+pub use government::*;
+pub mod law;
+pub use law::*;
+pub mod military;
+pub use military::*;
+pub mod nobility;
+pub use nobility::*;
+pub mod polity;
+pub use polity::*;
 pub mod sector;
 pub use sector::*;
+pub mod sophont;
+pub use sophont::*;
+pub mod starport;
+pub use starport::*;
 pub mod star;
 pub use star::*;
+pub mod structure;
+pub use structure::*;
 pub mod system;
 pub use system::*;
+pub mod trade;
+pub use trade::*;
+pub mod trade_code;
+pub use trade_code::*;
+pub mod traffic;
+pub use traffic::*;
 pub mod world;
 pub use world::*;
 pub mod rng;
@@ -0,0 +1,179 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::prelude::*;
+
+/// How a polity hands out noble titles, written with the T5 nobility codes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NobilityRules {
+    /// No hereditary nobility at all.
+    None,
+    /// The T5 Imperial noble table, from knights up to the emperor.
+    Imperial,
+    /// Titles only at seats of government, using the given code for each rank.
+    Capitals {
+        subsector: char,
+        sector: char,
+        polity: char,
+    },
+}
+
+impl NobilityRules {
+    /// The customary rules for a kind of polity.
+    pub fn for_kind(kind: PolityKind) -> Self {
+        match kind {
+            PolityKind::Imperium => NobilityRules::Imperial,
+            PolityKind::Hegemony | PolityKind::Principality => NobilityRules::Capitals {
+                subsector: 'F',
+                sector: 'G',
+                polity: 'H',
+            },
+            PolityKind::Federation | PolityKind::League | PolityKind::Republic => {
+                NobilityRules::None
+            }
+        }
+    }
+
+    /// The customary rules for an allegiance code, with none for non-aligned systems.
+    pub fn for_allegiance(allegiance: &str) -> Self {
+        PolityKind::from_code(allegiance)
+            .map(Self::for_kind)
+            .unwrap_or(NobilityRules::None)
+    }
+
+    pub fn titles(&self, sys: &System) -> String {
+        match self {
+            NobilityRules::None => String::new(),
+            NobilityRules::Imperial => imperial_titles(sys),
+            NobilityRules::Capitals {
+                subsector,
+                sector,
+                polity,
+            } => match sys.capital {
+                Some(Capital::Subsector) => subsector.to_string(),
+                Some(Capital::Sector) => sector.to_string(),
+                Some(Capital::Polity) => polity.to_string(),
+                None => String::new(),
+            },
+        }
+    }
+}
+
+fn imperial_titles(sys: &System) -> String {
+    let mut ns = Vec::new();
+
+    if sys.has_knight() {
+        ns.push("B")
+    }
+
+    if sys.has_baronet() {
+        ns.push("c")
+    }
+
+    if sys.has_baron() {
+        ns.push("C")
+    }
+
+    if sys.has_marquis() {
+        ns.push("D")
+    }
+
+    if sys.has_viscount() {
+        ns.push("e")
+    }
+
+    if sys.has_count() {
+        ns.push("E")
+    }
+
+    if sys.has_duke() {
+        ns.push("f")
+    }
+
+    if sys.has_subsector_duke() {
+        ns.push("F")
+    }
+
+    if sys.has_archduke() {
+        ns.push("G")
+    }
+
+    if sys.has_emperor() {
+        ns.push("H")
+    }
+
+    ns.join("")
+}
+
+impl Display for NobilityRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NobilityRules::None => write!(f, "none"),
+            NobilityRules::Imperial => write!(f, "imperial"),
+            NobilityRules::Capitals { .. } => write!(f, "capitals"),
+        }
+    }
+}
+
+impl FromStr for NobilityRules {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(NobilityRules::None),
+            "imperial" => Ok(NobilityRules::Imperial),
+            "capitals" => Ok(NobilityRules::for_kind(PolityKind::Principality)),
+            _ => Err(format!("Unknown nobility rules '{s}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capital_of(allegiance: &str) -> System {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.allegiance = String::from(allegiance);
+        sys.capital = Some(Capital::Polity);
+        sys
+    }
+
+    fn customary_titles(sys: &System) -> String {
+        sys.nobility(&NobilityRules::for_allegiance(&sys.allegiance))
+    }
+
+    #[test]
+    fn test_imperial_knights_and_emperor() {
+        let sys = capital_of("ImAa");
+        let titles = customary_titles(&sys);
+        assert!(titles.starts_with('B'));
+        assert!(titles.ends_with('H'));
+    }
+
+    #[test]
+    fn test_republics_have_no_nobles() {
+        assert_eq!(customary_titles(&capital_of("RpAa")), "");
+        assert_eq!(customary_titles(&capital_of(NON_ALIGNED)), "");
+    }
+
+    #[test]
+    fn test_capital_titles() {
+        assert_eq!(customary_titles(&capital_of("PrAa")), "H");
+        let mut sys = capital_of("PrAa");
+        sys.capital = None;
+        assert_eq!(customary_titles(&sys), "");
+    }
+
+    #[test]
+    fn test_sector_uses_overridden_rules() {
+        let seed = Seed::new(String::from("test"));
+        let sector = generate_sector(seed, Coordinate::new(0, 0), 32, 40, &Density::Dense)
+            .with_nobility(NobilityRules::None);
+        assert!(sector
+            .systems
+            .iter()
+            .all(|sys| sector.nobility(sys).is_empty()));
+    }
+}
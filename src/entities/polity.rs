@@ -0,0 +1,103 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+/// Allegiance code used by systems that no polity has claimed.
+pub const NON_ALIGNED: &str = "--";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PolityKind {
+    Imperium,
+    Federation,
+    Hegemony,
+    League,
+    Republic,
+    Principality,
+}
+
+impl PolityKind {
+    pub const ALL: [PolityKind; 6] = [
+        PolityKind::Imperium,
+        PolityKind::Federation,
+        PolityKind::Hegemony,
+        PolityKind::League,
+        PolityKind::Republic,
+        PolityKind::Principality,
+    ];
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        PolityKind::ALL
+            .into_iter()
+            .find(|kind| code.starts_with(kind.prefix()))
+    }
+
+    /// First half of the four character allegiance code.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            PolityKind::Imperium => "Im",
+            PolityKind::Federation => "Fd",
+            PolityKind::Hegemony => "Hg",
+            PolityKind::League => "Lg",
+            PolityKind::Republic => "Rp",
+            PolityKind::Principality => "Pr",
+        }
+    }
+}
+
+impl Display for PolityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            PolityKind::Imperium => "Imperium",
+            PolityKind::Federation => "Federation",
+            PolityKind::Hegemony => "Hegemony",
+            PolityKind::League => "League",
+            PolityKind::Republic => "Republic",
+            PolityKind::Principality => "Principality",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Administrative seats, from the least to the most senior.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Capital {
+    Subsector,
+    Sector,
+    Polity,
+}
+
+impl Capital {
+    pub fn trade_code(&self) -> TradeCode {
+        match self {
+            Capital::Subsector => TradeCode::SubsectorCapital,
+            Capital::Sector => TradeCode::SectorCapital,
+            Capital::Polity => TradeCode::Capital,
+        }
+    }
+}
+
+impl Display for Capital {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.trade_code())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polity {
+    pub code: String,
+    pub name: String,
+    pub kind: PolityKind,
+    /// The world the polity expanded from.
+    pub origin: Coordinate,
+    /// Seat of government, which may have moved away from the origin.
+    pub capital: Coordinate,
+    /// Jump range of the polity's ships, which limits how far it can expand in one step.
+    pub jump: i32,
+    pub nobility: NobilityRules,
+}
+
+impl Display for Polity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:4} {} {}", self.code, self.name, self.kind)
+    }
+}
@@ -2,10 +2,12 @@ use rand::{Rng, RngCore, SeedableRng};
 use rand_seeder::{Seeder, SipHasher, SipRng};
 use std::hash::{Hash, Hasher};
 
+use crate::prelude::*;
+
 pub type Dice = SipRng;
 pub type HashAlgo = SipHasher;
 
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Seed {
     pub top_level_seed: String,
     seed: u64,
@@ -42,6 +44,11 @@ impl Seed {
         }
     }
 
+    /// A seed for things that change from day to day, such as prices and traffic.
+    pub fn for_date(&self, date: Date) -> Self {
+        self.subseed(vec![date.ordinal()])
+    }
+
     pub fn to_rng(&self) -> Dice {
         Seeder::from(self.seed).make_rng()
     }
@@ -56,15 +63,15 @@ pub trait Rollable {
 impl Rollable for Dice {
     fn roll_dice(&mut self, num: usize, sides: i32) -> Vec<i32> {
         if sides < 1 || num < 1 {
-            return vec![0];
+            vec![0]
         } else {
-            return (0..num).map(|_| self.gen_range(1..=sides)).collect();
+            (0..num).map(|_| self.gen_range(1..=sides)).collect()
         }
     }
 
     fn roll(&mut self, num: usize, sides: i32, modifier: i32) -> i32 {
         if sides < 1 || num < 1 {
-            return 0;
+            0
         } else {
             self.roll_dice(num, sides).into_iter().sum::<i32>() + modifier
         }
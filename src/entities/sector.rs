@@ -2,10 +2,207 @@ use std::fmt::Display;
 
 use crate::prelude::*;
 
+/// Hexes per sector along each axis, matching the layout used by `Galaxy::get_sector`.
+pub const SECTOR_ROWS: i32 = 32;
+pub const SECTOR_COLS: i32 = 40;
+/// Sectors are split into a four by four grid of subsectors, lettered A to P.
+pub const SUBSECTOR_ROWS: i32 = 8;
+pub const SUBSECTOR_COLS: i32 = 10;
+
 #[derive(Debug, Clone)]
 pub struct Sector {
     pub seed: Seed,
+    pub location: Coordinate,
     pub systems: Vec<System>,
+    pub polities: Vec<Polity>,
+    pub sophonts: Vec<Sophont>,
+    /// Empty hexes with something in them.
+    pub deep_space: Vec<DeepSpace>,
+}
+
+impl Sector {
+    pub fn polity(&self, code: &str) -> Option<&Polity> {
+        self.polities.iter().find(|p| p.code == code)
+    }
+
+    pub fn members<'a>(&'a self, code: &'a str) -> impl Iterator<Item = &'a System> {
+        self.systems
+            .iter()
+            .filter(move |sys| sys.allegiance == code)
+    }
+
+    /// Titles on a system under the rules of the polity it belongs to.
+    pub fn nobility(&self, sys: &System) -> String {
+        match self.polity(&sys.allegiance) {
+            Some(polity) => sys.nobility(&polity.nobility),
+            None => String::new(),
+        }
+    }
+
+    /// Replaces the nobility rules of every polity in the sector.
+    pub fn with_nobility(mut self, rules: NobilityRules) -> Self {
+        for polity in self.polities.iter_mut() {
+            polity.nobility = rules;
+        }
+        self
+    }
+
+    pub fn economy(&self) -> Economy {
+        Economy::new(&self.systems)
+    }
+
+    pub fn subsector_economy(&self, subsector: char) -> Economy {
+        Economy::new(
+            self.systems
+                .iter()
+                .filter(|sys| sys.location.subsector() == subsector),
+        )
+    }
+
+    pub fn polity_economy(&self, code: &str) -> Economy {
+        Economy::new(self.members(code))
+    }
+
+    /// Population and product of the sector, each subsector and each polity,
+    /// followed by the forces and defence budget of each polity.
+    pub fn economy_report(&self) -> String {
+        let subsectors =
+            ('A'..='P').map(|subsector| (subsector.to_string(), self.subsector_economy(subsector)));
+        let polities = self
+            .polities
+            .iter()
+            .map(|polity| (polity.code.clone(), self.polity_economy(&polity.code)));
+        let economies = std::iter::once((String::from("Sector"), self.economy()))
+            .chain(subsectors)
+            .chain(polities)
+            .map(|(label, economy)| format!("{label:6} {economy}"));
+        let forces = self
+            .polities
+            .iter()
+            .map(|polity| format!("{:6} {}", "Forces", self.forces(&polity.code)));
+        economies.chain(forces).collect::<Vec<String>>().join("\n")
+    }
+
+    pub fn forces(&self, code: &str) -> PolityForces {
+        PolityForces::new(code, self.members(code))
+    }
+
+    /// Shortest chain of jumps between two hexes, refuelling at systems and
+    /// deep space hexes that offer fuel. Includes both ends.
+    ///
+    /// Polity growth and scout routes are settled before deep space is
+    /// generated, since depots take the allegiance of nearby systems, so only
+    /// routes planned with this method make use of depots.
+    pub fn jump_route(
+        &self,
+        from: Coordinate,
+        to: Coordinate,
+        jump: i32,
+    ) -> Option<Vec<Coordinate>> {
+        let stops: Vec<Coordinate> = self
+            .systems
+            .iter()
+            .filter(|sys| sys.has_fuel())
+            .map(|sys| sys.location)
+            .chain(
+                self.deep_space
+                    .iter()
+                    .filter(|hex| hex.has_fuel())
+                    .map(|hex| hex.location),
+            )
+            .chain([to])
+            .collect();
+
+        let mut previous: Vec<Option<usize>> = vec![None; stops.len()];
+        let mut visited = vec![false; stops.len()];
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back(None);
+        while let Some(current) = queue.pop_front() {
+            let here = current.map_or(from, |i| stops[i]);
+            if here == to {
+                let mut route = vec![to];
+                let mut step = current.and_then(|i| previous[i]);
+                while let Some(i) = step {
+                    route.push(stops[i]);
+                    step = previous[i];
+                }
+                if from != to {
+                    route.push(from);
+                }
+                route.reverse();
+                return Some(route);
+            }
+            for (i, stop) in stops.iter().enumerate() {
+                if !visited[i] && *stop != from && here.distance(stop) <= jump {
+                    visited[i] = true;
+                    previous[i] = current;
+                    queue.push_back(Some(i));
+                }
+            }
+        }
+        None
+    }
+
+    pub fn borders(&self) -> Vec<Border> {
+        compute_borders(
+            self.systems
+                .iter()
+                .map(|sys| (sys.location, sys.allegiance.as_str())),
+            |hex| (1..=SECTOR_ROWS).contains(&hex.row) && (1..=SECTOR_COLS).contains(&hex.col),
+        )
+    }
+
+    /// Traveller Map sector metadata holding the allegiances and their borders.
+    pub fn metadata_xml(&self) -> String {
+        let allegiances = self
+            .polities
+            .iter()
+            .map(|p| {
+                let economy = self.polity_economy(&p.code);
+                format!(
+                    "    <Allegiance Code=\"{}\" Population=\"{}\" GWP=\"{}\">{} {}</Allegiance>",
+                    p.code, economy.population, economy.gwp, p.name, p.kind
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let borders = self
+            .borders()
+            .iter()
+            .map(|b| b.to_xml())
+            .flat_map(|xml| {
+                xml.lines()
+                    .map(|line| format!("    {line}"))
+                    .collect::<Vec<String>>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            "<?xml version=\"1.0\"?>\n<Sector>\n  <Allegiances>\n{allegiances}\n  </Allegiances>\n  <Borders>\n{borders}\n  </Borders>\n</Sector>"
+        )
+    }
+}
+
+/// Borders across several sectors at once, in galaxy wide hex coordinates.
+pub fn region_borders(sectors: &[Sector]) -> Vec<Border> {
+    let claims = sectors.iter().flat_map(|sector| {
+        sector.systems.iter().map(|sys| {
+            (
+                sys.location.global(sector.location),
+                sys.allegiance.as_str(),
+            )
+        })
+    });
+    // Hex 01 of each sector follows on from the last hex of the one before
+    let in_region = |hex: &Coordinate| {
+        let sector = Coordinate::new(
+            (hex.row - 1).div_euclid(SECTOR_ROWS),
+            (hex.col - 1).div_euclid(SECTOR_COLS),
+        );
+        sectors.iter().any(|s| s.location == sector)
+    };
+    compute_borders(claims, in_region)
 }
 
 impl Display for Sector {
@@ -21,10 +218,8 @@ impl Display for Sector {
         let ex_dash: String = "-".repeat(7);
         let cx: String = format!("{:6}", "[Cx]");
         let cx_dash: String = "-".repeat(6);
-        let bases: String = format!("{:2}", "B");
-        let bases_dash: String = "-".repeat(2);
         let zone: String = format!("{:1}", "Z");
-        let zone_dash: String = "-".repeat(1);
+        let zone_dash: String = "-".to_string();
         let pbg: String = format!("{:3}", "PBG");
         let pbg_dash: String = "-".repeat(3);
         let worlds: String = format!("{:2}", "W");
@@ -36,8 +231,7 @@ impl Display for Sector {
             .systems
             .iter()
             .map(|sys| sys.name.len())
-            .reduce(|acc, x| if x > acc { x } else { acc })
-            .unwrap();
+            .fold("Name".len(), |acc, x| if x > acc { x } else { acc });
 
         let name = format!("{:width$}", "Name", width = name_width);
         let name_dash = "-".repeat(name_width);
@@ -45,9 +239,8 @@ impl Display for Sector {
         let remarks_width = self
             .systems
             .iter()
-            .map(|sys| sys.trade_codes().len())
-            .reduce(|acc, x| if x > acc { x } else { acc })
-            .unwrap();
+            .map(|sys| sys.remarks().len())
+            .fold("Remarks".len(), |acc, x| if x > acc { x } else { acc });
 
         let remarks = format!("{:width$}", "Remarks", width = remarks_width);
         let remarks_dash = "-".repeat(remarks_width);
@@ -55,19 +248,26 @@ impl Display for Sector {
         let nobility_width = self
             .systems
             .iter()
-            .map(|sys| sys.nobility().len())
-            .reduce(|acc, x| if x > acc { x } else { acc })
-            .unwrap();
+            .map(|sys| self.nobility(sys).len())
+            .fold("N".len(), |acc, x| if x > acc { x } else { acc });
 
         let nobility = format!("{:width$}", "N", width = nobility_width);
         let nobility_dash = "-".repeat(nobility_width);
 
+        let bases_width = self
+            .systems
+            .iter()
+            .map(|sys| sys.bases().len())
+            .fold(2, |acc, x| if x > acc { x } else { acc });
+
+        let bases = format!("{:width$}", "B", width = bases_width);
+        let bases_dash = "-".repeat(bases_width);
+
         let stellar_width = self
             .systems
             .iter()
             .map(|sys| sys.stars().len())
-            .reduce(|acc, x| if x > acc { x } else { acc })
-            .unwrap();
+            .fold("Stellar".len(), |acc, x| if x > acc { x } else { acc });
 
         let stellar = format!("{:width$}", "Stellar", width = stellar_width);
         let stellar_dash = "-".repeat(stellar_width);
@@ -111,18 +311,19 @@ impl Display for Sector {
 
         write!(
             f,
-            "{}\n{}",
-            format!("{}\n{}", labels, separator),
+            "{}\n{}\n{}",
+            labels,
+            separator,
             self.systems
                 .iter()
                 .map(|sys| format!(
-                    "{} {:name_width$} {:10} {:remarks_width$} {} {:nobility_width$} {:2} {:1} {:3} {:2} {:4} {:stellar_width$}",
+                    "{} {:name_width$} {:10} {:remarks_width$} {} {:nobility_width$} {:bases_width$} {:1} {:3} {:2} {:4} {:stellar_width$}",
                     sys.location,
                     sys.name,
                     sys.mainworld,
-                    sys.trade_codes(),
+                    sys.remarks(),
                     sys.extensions,
-                    sys.nobility(),
+                    self.nobility(sys),
                     sys.bases(),
                     sys.travel_zone(),
                     sys.pbg(),
@@ -132,6 +333,41 @@ impl Display for Sector {
                 ))
                 .collect::<Vec<String>>()
                 .join("\n")
-        )
+        )?;
+
+        if !self.polities.is_empty() {
+            write!(f, "\n\nAllegiances")?;
+            for polity in &self.polities {
+                write!(f, "\n{polity}")?;
+            }
+        }
+
+        if !self.sophonts.is_empty() {
+            write!(f, "\n\nSophonts")?;
+            for sophont in &self.sophonts {
+                write!(f, "\n{sophont}")?;
+            }
+        }
+
+        let sites = self
+            .systems
+            .iter()
+            .filter_map(|sys| sys.ancient_site.as_ref().map(|site| (sys.location, site)))
+            .collect::<Vec<(Coordinate, &AncientSite)>>();
+        if !sites.is_empty() {
+            write!(f, "\n\nAncient sites")?;
+            for (hex, site) in sites {
+                write!(f, "\n{hex} {site}")?;
+            }
+        }
+
+        if !self.deep_space.is_empty() {
+            write!(f, "\n\nDeep space")?;
+            for hex in &self.deep_space {
+                write!(f, "\n{hex}")?;
+            }
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,160 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BodyPlan {
+    Humanoid,
+    Quadruped,
+    Hexapod,
+    Avian,
+    Aquatic,
+    Radial,
+    Serpentine,
+}
+
+impl Display for BodyPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BodyPlan::Humanoid => write!(f, "Humanoid"),
+            BodyPlan::Quadruped => write!(f, "Quadruped"),
+            BodyPlan::Hexapod => write!(f, "Hexapod"),
+            BodyPlan::Avian => write!(f, "Avian"),
+            BodyPlan::Aquatic => write!(f, "Aquatic"),
+            BodyPlan::Radial => write!(f, "Radial"),
+            BodyPlan::Serpentine => write!(f, "Serpentine"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SophontSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Display for SophontSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SophontSize::Small => write!(f, "Small"),
+            SophontSize::Medium => write!(f, "Medium"),
+            SophontSize::Large => write!(f, "Large"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SocialStructure {
+    Solitary,
+    Familial,
+    Tribal,
+    Hive,
+}
+
+impl Display for SocialStructure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SocialStructure::Solitary => write!(f, "Solitary"),
+            SocialStructure::Familial => write!(f, "Familial"),
+            SocialStructure::Tribal => write!(f, "Tribal"),
+            SocialStructure::Hive => write!(f, "Hive"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SophontTraits {
+    pub body: BodyPlan,
+    pub size: SophontSize,
+    /// What their animal ancestors ate.
+    pub diet: Diet,
+    pub social: SocialStructure,
+}
+
+impl Display for SophontTraits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}, {}",
+            self.size, self.body, self.diet, self.social
+        )
+    }
+}
+
+/// A native intelligent species.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sophont {
+    /// Four letter code used in population remarks, e.g. "Asla".
+    pub code: String,
+    pub name: String,
+    pub homeworld: Coordinate,
+    pub traits: SophontTraits,
+}
+
+impl Display for Sophont {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} ({}) {}",
+            self.code, self.name, self.homeworld, self.traits
+        )
+    }
+}
+
+/// The share of a world's population belonging to one non-human species.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SophontPopulation {
+    pub code: String,
+    pub name: String,
+    /// Tenths of the population, where 0 means present but under a tenth.
+    pub tenths: i32,
+    pub homeworld: bool,
+}
+
+impl SophontPopulation {
+    fn share(&self) -> String {
+        match self.tenths {
+            10.. => String::from("W"),
+            tenths => tenths.max(0).to_string(),
+        }
+    }
+
+    /// T5 remark: (Name) for a homeworld, otherwise the code, each followed by the share.
+    ///
+    /// A homeworld entirely populated by its natives carries no share digit.
+    pub fn remark(&self) -> String {
+        match (self.homeworld, self.tenths) {
+            (true, 10..) => format!("({})", self.name),
+            (true, _) => format!("({}){}", self.name, self.share()),
+            (false, _) => format!("{}{}", self.code, self.share()),
+        }
+    }
+}
+
+impl Display for SophontPopulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.remark())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remarks() {
+        let mut population = SophontPopulation {
+            code: String::from("Asla"),
+            name: String::from("Aslan"),
+            tenths: 10,
+            homeworld: true,
+        };
+        assert_eq!(population.remark(), "(Aslan)");
+        population.tenths = 7;
+        assert_eq!(population.remark(), "(Aslan)7");
+        population.homeworld = false;
+        population.tenths = 0;
+        assert_eq!(population.remark(), "Asla0");
+    }
+}
@@ -11,8 +11,8 @@ impl Star {
     pub fn class(&self) -> &Class {
         match &self {
             Star::BrownDwarf => &Class::BD,
-            Star::Dwarf(class) => &class,
-            Star::Star(class, _, _) => &class,
+            Star::Dwarf(class) => class,
+            Star::Star(class, _, _) => class,
         }
     }
 
@@ -20,7 +20,7 @@ impl Star {
         match &self {
             Star::BrownDwarf => &Size::D,
             Star::Dwarf(_) => &Size::D,
-            Star::Star(_, _, size) => &size,
+            Star::Star(_, _, size) => size,
         }
     }
 
@@ -0,0 +1,210 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Starports A to X serve mainworlds; spaceports F to Y serve the system's other worlds.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Starport {
+    A,
+    B,
+    C,
+    D,
+    E,
+    X,
+    F,
+    G,
+    H,
+    Y,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FuelQuality {
+    Refined,
+    Unrefined,
+    None,
+}
+
+/// What the port's yards can build.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Shipyard {
+    Starships,
+    Spacecraft,
+    None,
+}
+
+/// The worst damage the port can repair.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Repair {
+    Overhaul,
+    Major,
+    Minor,
+    None,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Facilities {
+    pub fuel: FuelQuality,
+    pub shipyard: Shipyard,
+    pub repair: Repair,
+    /// An orbital station.
+    pub highport: bool,
+    /// A surface port.
+    pub downport: bool,
+}
+
+impl Starport {
+    pub const ALL: [Starport; 10] = [
+        Starport::A,
+        Starport::B,
+        Starport::C,
+        Starport::D,
+        Starport::E,
+        Starport::X,
+        Starport::F,
+        Starport::G,
+        Starport::H,
+        Starport::Y,
+    ];
+
+    pub fn is_spaceport(&self) -> bool {
+        matches!(self, Starport::F | Starport::G | Starport::H | Starport::Y)
+    }
+
+    pub fn quality(&self) -> &'static str {
+        match self {
+            Starport::A => "Excellent",
+            Starport::B => "Good",
+            Starport::C => "Routine",
+            Starport::D => "Poor",
+            Starport::E => "Frontier",
+            Starport::X => "None",
+            Starport::F => "Good",
+            Starport::G => "Poor",
+            Starport::H => "Primitive",
+            Starport::Y => "None",
+        }
+    }
+
+    pub fn facilities(&self) -> Facilities {
+        let (fuel, shipyard, repair, highport, downport) = match self {
+            Starport::A => (
+                FuelQuality::Refined,
+                Shipyard::Starships,
+                Repair::Overhaul,
+                true,
+                true,
+            ),
+            Starport::B => (
+                FuelQuality::Refined,
+                Shipyard::Spacecraft,
+                Repair::Overhaul,
+                true,
+                true,
+            ),
+            Starport::C => (
+                FuelQuality::Unrefined,
+                Shipyard::None,
+                Repair::Major,
+                false,
+                true,
+            ),
+            Starport::D | Starport::F => (
+                FuelQuality::Unrefined,
+                Shipyard::None,
+                Repair::Minor,
+                false,
+                true,
+            ),
+            Starport::G => (
+                FuelQuality::Unrefined,
+                Shipyard::None,
+                Repair::None,
+                false,
+                true,
+            ),
+            Starport::E | Starport::H => {
+                (FuelQuality::None, Shipyard::None, Repair::None, false, true)
+            }
+            Starport::X | Starport::Y => (
+                FuelQuality::None,
+                Shipyard::None,
+                Repair::None,
+                false,
+                false,
+            ),
+        };
+        Facilities {
+            fuel,
+            shipyard,
+            repair,
+            highport,
+            downport,
+        }
+    }
+
+    pub fn has_refined_fuel(&self) -> bool {
+        self.facilities().fuel == FuelQuality::Refined
+    }
+
+    pub fn has_fuel(&self) -> bool {
+        self.facilities().fuel != FuelQuality::None
+    }
+}
+
+impl Display for Starport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = match self {
+            Starport::A => "A",
+            Starport::B => "B",
+            Starport::C => "C",
+            Starport::D => "D",
+            Starport::E => "E",
+            Starport::X => "X",
+            Starport::F => "F",
+            Starport::G => "G",
+            Starport::H => "H",
+            Starport::Y => "Y",
+        };
+        f.pad(code)
+    }
+}
+
+impl FromStr for Starport {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Starport::ALL
+            .into_iter()
+            .find(|port| port.to_string() == s)
+            .ok_or_else(|| format!("Unknown starport '{s}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_starport() {
+        for port in Starport::ALL {
+            assert_eq!(port.to_string().parse::<Starport>(), Ok(port))
+        }
+    }
+
+    #[test]
+    fn test_facilities_decline_with_class() {
+        let mainworld = [
+            Starport::A,
+            Starport::B,
+            Starport::C,
+            Starport::D,
+            Starport::E,
+            Starport::X,
+        ];
+        for pair in mainworld.windows(2) {
+            let (better, worse) = (pair[0].facilities(), pair[1].facilities());
+            assert!(better.fuel <= worse.fuel);
+            assert!(better.shipyard <= worse.shipyard);
+            assert!(better.repair <= worse.repair);
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::f64::consts::PI;
+use std::fmt::Display;
+
+use rand::RngCore;
+
+use crate::prelude::*;
+
+/// The broad age of the stars in a region, which skews spectral class rolls.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum StellarPopulation {
+    /// Star forming regions in the spiral arms, rich in O and B stars.
+    Young,
+    #[default]
+    Disc,
+    /// The core and the voids, dominated by K and M stars.
+    Old,
+}
+
+impl StellarPopulation {
+    pub fn spectral_dm(&self) -> i32 {
+        match self {
+            StellarPopulation::Young => -1,
+            StellarPopulation::Disc => 0,
+            StellarPopulation::Old => 1,
+        }
+    }
+}
+
+impl Display for StellarPopulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StellarPopulation::Young => "young",
+            StellarPopulation::Disc => "disc",
+            StellarPopulation::Old => "old",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A seeded, continuous density field with a core, spiral arms and voids.
+///
+/// The field is sampled in galaxy wide parsecs so neighbouring sectors blend smoothly.
+#[derive(Debug, Clone)]
+pub struct GalacticStructure {
+    seed: Seed,
+    /// Centre of the galactic core, in parsecs from hex 0000 of sector 0000.
+    pub core: (f64, f64),
+    pub core_radius: f64,
+    pub disc_radius: f64,
+    pub arms: u32,
+    /// How tightly the arms wind; smaller values wind tighter.
+    pub pitch: f64,
+    /// Size in parsecs of the features produced by the noise.
+    pub noise_scale: f64,
+}
+
+impl GalacticStructure {
+    pub fn new(seed: Seed) -> Self {
+        Self {
+            seed,
+            core: (0.0, 0.0),
+            core_radius: 60.0,
+            disc_radius: 900.0,
+            arms: 2,
+            pitch: 0.25,
+            noise_scale: 24.0,
+        }
+    }
+
+    pub fn position(sector: Coordinate, hex: Coordinate) -> (f64, f64) {
+        let global = hex.global(sector);
+        (global.col as f64, global.row as f64)
+    }
+
+    fn offset(&self, sector: Coordinate, hex: Coordinate) -> (f64, f64) {
+        let (x, y) = Self::position(sector, hex);
+        (x - self.core.0, y - self.core.1)
+    }
+
+    pub fn core_strength(&self, sector: Coordinate, hex: Coordinate) -> f64 {
+        let (dx, dy) = self.offset(sector, hex);
+        let r = (dx * dx + dy * dy).sqrt();
+        (-(r / self.core_radius).powi(2)).exp()
+    }
+
+    pub fn arm_strength(&self, sector: Coordinate, hex: Coordinate) -> f64 {
+        let (dx, dy) = self.offset(sector, hex);
+        let r = (dx * dx + dy * dy).sqrt().max(1.0);
+        let theta = dy.atan2(dx);
+        // Distance along a logarithmic spiral, folded into the gap between two arms
+        let phase = self.arms as f64 * (theta - r.ln() / self.pitch);
+        // Roughen the arm edges so they don't look drawn with a compass
+        let wobble = (self.noise(dx / 2.0, dy / 2.0, 1) - 0.5) * PI;
+        ((1.0 + (phase + wobble).cos()) / 2.0).powi(3)
+    }
+
+    /// Stellar density of a hex as a value between 0 (empty space) and 1 (a dense cluster).
+    pub fn field(&self, sector: Coordinate, hex: Coordinate) -> f64 {
+        let (dx, dy) = self.offset(sector, hex);
+        let r = (dx * dx + dy * dy).sqrt();
+        let disc = (-r / self.disc_radius).exp();
+        let arms = self.arm_strength(sector, hex);
+        let core = self.core_strength(sector, hex);
+        let noise = self.noise(dx, dy, 2) - 0.5;
+
+        (disc * (0.35 + 0.3 * arms) + 0.5 * core + 0.4 * noise).clamp(0.0, 1.0)
+    }
+
+    fn noise(&self, x: f64, y: f64, octaves: u32) -> f64 {
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut weight = 0.0;
+        for octave in 0..octaves {
+            let scale = self.noise_scale / 2f64.powi(octave as i32);
+            total += amplitude * self.value_noise(x / scale, y / scale, octave);
+            weight += amplitude;
+            amplitude /= 2.0;
+        }
+        total / weight
+    }
+
+    fn value_noise(&self, x: f64, y: f64, octave: u32) -> f64 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (tx, ty) = (smooth(x - x0), smooth(y - y0));
+        let (x0, y0) = (x0 as i64, y0 as i64);
+
+        let top = lerp(
+            self.lattice(x0, y0, octave),
+            self.lattice(x0 + 1, y0, octave),
+            tx,
+        );
+        let bottom = lerp(
+            self.lattice(x0, y0 + 1, octave),
+            self.lattice(x0 + 1, y0 + 1, octave),
+            tx,
+        );
+        lerp(top, bottom, ty)
+    }
+
+    fn lattice(&self, x: i64, y: i64, octave: u32) -> f64 {
+        let value = self.seed.subseed(vec![(x, y, octave)]).to_rng().next_u32();
+        value as f64 / u32::MAX as f64
+    }
+}
+
+fn smooth(t: f64) -> f64 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+impl DensityMap for GalacticStructure {
+    fn density(&self, sector: Coordinate, hex: Coordinate) -> Density {
+        match self.field(sector, hex) {
+            x if x < 0.12 => Density::Rift,
+            x if x < 0.25 => Density::Sparse,
+            x if x < 0.45 => Density::Standard,
+            x if x < 0.65 => Density::Dense,
+            _ => Density::Cluster,
+        }
+    }
+
+    fn population(&self, sector: Coordinate, hex: Coordinate) -> StellarPopulation {
+        if self.core_strength(sector, hex) > 0.5 {
+            StellarPopulation::Old
+        } else if self.arm_strength(sector, hex) > 0.6 {
+            StellarPopulation::Young
+        } else if self.field(sector, hex) < 0.12 {
+            StellarPopulation::Old
+        } else {
+            StellarPopulation::Disc
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> GalacticStructure {
+        GalacticStructure::new(Seed::new(String::from("test")))
+    }
+
+    #[test]
+    fn test_field_is_deterministic() {
+        let sector = Coordinate::new(2, -3);
+        let hex = Coordinate::new(10, 20);
+        assert_eq!(setup().field(sector, hex), setup().field(sector, hex))
+    }
+
+    #[test]
+    fn test_field_blends_across_sectors() {
+        let structure = setup();
+        for row in 1..=SECTOR_ROWS {
+            let west = structure.field(Coordinate::new(0, 0), Coordinate::new(row, SECTOR_COLS));
+            let east = structure.field(Coordinate::new(0, 1), Coordinate::new(row, 1));
+            assert!((west - east).abs() < 0.1)
+        }
+    }
+
+    #[test]
+    fn test_core_is_dense_and_old() {
+        let structure = setup();
+        let origin = Coordinate::new(0, 0);
+        assert!(structure.density(origin, origin) >= Density::Dense);
+        assert_eq!(structure.population(origin, origin), StellarPopulation::Old)
+    }
+}
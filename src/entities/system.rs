@@ -2,21 +2,30 @@ use crate::prelude::*;
 
 #[derive(Debug, Clone)]
 pub struct System {
+    pub seed: Seed,
     pub location: Coordinate,
     pub name: String,
     pub stars: Vec<Star>,
     pub mainworld: World,
+    pub secondary_worlds: Vec<World>,
     pub extensions: Extensions,
     pub worlds: i32,
     pub belts: i32,
     pub gas_giants: i32,
     pub allegiance: String,
+    pub capital: Option<Capital>,
+    pub ancient_site: Option<AncientSite>,
+    /// Non-human populations, homeworld natives first.
+    pub sophonts: Vec<SophontPopulation>,
 }
 
 impl System {
+    pub fn is_aligned(&self) -> bool {
+        self.allegiance != NON_ALIGNED
+    }
+
     pub fn has_knight(&self) -> bool {
-        //TODO: Check allegiance eventually
-        true
+        self.is_aligned()
     }
     pub fn has_baronet(&self) -> bool {
         self.mainworld.is_pre_rich() || self.mainworld.is_preagricultural()
@@ -42,6 +51,28 @@ impl System {
         self.extensions.importance > 3
     }
 
+    pub fn has_subsector_duke(&self) -> bool {
+        self.capital == Some(Capital::Subsector)
+    }
+
+    pub fn has_archduke(&self) -> bool {
+        self.capital == Some(Capital::Sector)
+    }
+
+    pub fn has_emperor(&self) -> bool {
+        self.capital == Some(Capital::Polity)
+    }
+
+    pub fn is_capital(&self) -> bool {
+        self.capital.is_some()
+    }
+
+    /// Whether a ship can refuel here: at the starport, by skimming a gas giant
+    /// or from the mainworld's water.
+    pub fn has_fuel(&self) -> bool {
+        self.mainworld.port.has_fuel() || self.gas_giants > 0 || self.mainworld.hydrographics > 0
+    }
+
     pub fn bases(&self) -> String {
         self.mainworld.bases_to_string()
     }
@@ -65,42 +96,102 @@ impl System {
             .join(" ")
     }
 
-    pub fn trade_codes(&self) -> String {
-        self.mainworld.trade_codes()
+    pub fn total_population(&self) -> i64 {
+        total_population(&self.mainworld)
+    }
+
+    pub fn per_capita_income(&self) -> i64 {
+        per_capita_income(self)
+    }
+
+    pub fn gross_world_product(&self) -> i64 {
+        gross_world_product(self)
     }
 
-    pub fn nobility(&self) -> String {
-        let mut ns = Vec::new();
+    pub fn military(&self) -> Military {
+        Military::new(self)
+    }
 
-        if self.has_knight() {
-            ns.push("B")
+    pub fn government(&self) -> Government {
+        Government::new(self)
+    }
+
+    pub fn culture(&self) -> Option<Culture> {
+        Culture::new(self)
+    }
+
+    pub fn biosphere(&self) -> Biosphere {
+        Biosphere::new(self, &self.mainworld)
+    }
+
+    pub fn is_research_station(&self) -> bool {
+        self.mainworld.has_scout_base()
+            && self.mainworld.tech >= 12
+            && (1..=6).contains(&self.mainworld.population)
+    }
+
+    pub fn is_data_repository(&self) -> bool {
+        self.capital == Some(Capital::Polity) && self.mainworld.tech >= 12
+    }
+
+    pub fn is_ancient_site(&self) -> bool {
+        self.ancient_site.as_ref().is_some_and(|site| site.major)
+    }
+
+    pub fn trade_codes(&self) -> TradeCodes {
+        let mut tc = self.mainworld.trade_codes();
+
+        if self.is_research_station() {
+            tc.insert(TradeCode::ResearchStation)
         }
 
-        if self.has_baronet() {
-            ns.push("c")
+        if self.is_data_repository() {
+            tc.insert(TradeCode::DataRepository)
         }
 
-        if self.has_baron() {
-            ns.push("C")
+        if self.is_ancient_site() {
+            tc.insert(TradeCode::AncientSite)
         }
 
-        if self.has_marquis() {
-            ns.push("D")
+        if let Some(capital) = self.capital {
+            tc.insert(capital.trade_code())
         }
 
-        if self.has_viscount() {
-            ns.push("e")
+        tc
+    }
+
+    /// Trade codes followed by sophont population codes, as in the remarks column.
+    pub fn remarks(&self) -> String {
+        [self.trade_codes().to_string()]
+            .into_iter()
+            .chain(self.sophonts.iter().map(|p| p.remark()))
+            .filter(|remark| !remark.is_empty())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Codes for one of the system's other worlds, which depend on the mainworld.
+    pub fn secondary_trade_codes(&self, world: &World) -> TradeCodes {
+        let mut tc = world.trade_codes();
+
+        if world.is_farming() {
+            tc.insert(TradeCode::Farming)
         }
 
-        if self.has_count() {
-            ns.push("E")
+        if world.is_mining(&self.mainworld) {
+            tc.insert(TradeCode::Mining)
         }
 
-        if self.has_duke() {
-            ns.push("f")
+        if world.is_penal_colony() {
+            tc.insert(TradeCode::PenalColony)
         }
 
-        ns.join("")
+        tc
+    }
+
+    /// Titles under the given rules; `Sector::nobility` picks the rules of the system's polity.
+    pub fn nobility(&self, rules: &NobilityRules) -> String {
+        rules.titles(self)
     }
 }
 
@@ -114,7 +205,8 @@ impl std::fmt::Display for System {
             self.mainworld,
             self.trade_codes(),
             self.extensions,
-            self.nobility(),
+            // Outside a sector there is no polity to consult, so use its customary rules
+            self.nobility(&NobilityRules::for_allegiance(&self.allegiance)),
             self.bases(),
             self.travel_zone(),
             self.pbg(),
@@ -0,0 +1,659 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+use TradeCode::*;
+use TradeTag::{AmberZone, Code, HighTech, LowTech, RedZone};
+
+/// Something about a world that makes a trade good available or changes its price.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TradeTag {
+    /// Every world.
+    All,
+    Code(TradeCode),
+    /// Tech level 12 or higher.
+    HighTech,
+    /// Tech level 5 or lower.
+    LowTech,
+    AmberZone,
+    RedZone,
+}
+
+impl TradeTag {
+    pub fn applies(&self, sys: &System) -> bool {
+        match self {
+            TradeTag::All => true,
+            TradeTag::Code(code) => sys.trade_codes().contains(*code),
+            TradeTag::HighTech => sys.mainworld.tech >= 12,
+            TradeTag::LowTech => sys.mainworld.tech <= 5,
+            TradeTag::AmberZone => sys.mainworld.travel_zone == TravelZone::Amber,
+            TradeTag::RedZone => sys.mainworld.travel_zone == TravelZone::Red,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct TradeGood {
+    /// D66 roll on the trade goods table.
+    pub id: i32,
+    pub name: &'static str,
+    pub availability: &'static [TradeTag],
+    /// Tons on offer: this many dice, times the multiplier.
+    pub tons: (usize, i32),
+    /// Credits per ton.
+    pub base_price: i64,
+    pub purchase_dms: &'static [(TradeTag, i32)],
+    pub sale_dms: &'static [(TradeTag, i32)],
+    pub illegal: bool,
+}
+
+impl TradeGood {
+    pub fn is_available(&self, sys: &System) -> bool {
+        self.availability.iter().any(|tag| tag.applies(sys))
+    }
+
+    fn best_dm(dms: &[(TradeTag, i32)], sys: &System) -> i32 {
+        dms.iter()
+            .filter(|(tag, _)| tag.applies(sys))
+            .map(|(_, dm)| *dm)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// How much cheaper the good is to buy on this world.
+    pub fn purchase_dm(&self, sys: &System) -> i32 {
+        Self::best_dm(self.purchase_dms, sys) - Self::best_dm(self.sale_dms, sys)
+    }
+
+    /// How much more the good fetches when sold on this world.
+    pub fn sale_dm(&self, sys: &System) -> i32 {
+        Self::best_dm(self.sale_dms, sys) - Self::best_dm(self.purchase_dms, sys)
+    }
+}
+
+impl Display for TradeGood {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.id, self.name)
+    }
+}
+
+pub const TRADE_GOODS: [TradeGood; 32] = [
+    TradeGood {
+        id: 11,
+        name: "Common Electronics",
+        availability: &[TradeTag::All],
+        tons: (2, 10),
+        base_price: 20_000,
+        purchase_dms: &[(Code(Industrial), 2), (HighTech, 3), (Code(Rich), 1)],
+        sale_dms: &[(Code(NonIndustrial), 2), (LowTech, 1), (Code(Poor), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 12,
+        name: "Common Industrial Goods",
+        availability: &[TradeTag::All],
+        tons: (2, 10),
+        base_price: 10_000,
+        purchase_dms: &[(Code(NonAgricultural), 2), (Code(Industrial), 5)],
+        sale_dms: &[(Code(NonIndustrial), 3), (Code(Agricultural), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 13,
+        name: "Common Manufactured Goods",
+        availability: &[TradeTag::All],
+        tons: (2, 10),
+        base_price: 20_000,
+        purchase_dms: &[(Code(NonAgricultural), 2), (Code(Industrial), 5)],
+        sale_dms: &[(Code(NonIndustrial), 3), (Code(HighPopulation), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 14,
+        name: "Common Raw Materials",
+        availability: &[TradeTag::All],
+        tons: (2, 20),
+        base_price: 5_000,
+        purchase_dms: &[(Code(Agricultural), 3), (Code(Garden), 2)],
+        sale_dms: &[(Code(Industrial), 2), (Code(Poor), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 15,
+        name: "Common Consumables",
+        availability: &[TradeTag::All],
+        tons: (2, 20),
+        base_price: 500,
+        purchase_dms: &[
+            (Code(Agricultural), 3),
+            (Code(WaterWorld), 2),
+            (Code(Garden), 1),
+            (Code(AsteroidBelt), -4),
+        ],
+        sale_dms: &[
+            (Code(AsteroidBelt), 1),
+            (Code(FluidOceans), 1),
+            (Code(IceCapped), 1),
+            (Code(HighPopulation), 1),
+        ],
+        illegal: false,
+    },
+    TradeGood {
+        id: 16,
+        name: "Common Ore",
+        availability: &[TradeTag::All],
+        tons: (2, 20),
+        base_price: 1_000,
+        purchase_dms: &[(Code(AsteroidBelt), 4)],
+        sale_dms: &[(Code(Industrial), 3), (Code(NonIndustrial), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 21,
+        name: "Advanced Electronics",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 5),
+        base_price: 100_000,
+        purchase_dms: &[(Code(Industrial), 2), (HighTech, 3)],
+        sale_dms: &[
+            (Code(NonIndustrial), 1),
+            (Code(Rich), 2),
+            (Code(AsteroidBelt), 3),
+        ],
+        illegal: false,
+    },
+    TradeGood {
+        id: 22,
+        name: "Advanced Machine Parts",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 5),
+        base_price: 75_000,
+        purchase_dms: &[(Code(Industrial), 2), (HighTech, 1)],
+        sale_dms: &[(Code(AsteroidBelt), 2), (Code(NonIndustrial), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 23,
+        name: "Advanced Manufactured Goods",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 5),
+        base_price: 100_000,
+        purchase_dms: &[(Code(Industrial), 1)],
+        sale_dms: &[(Code(HighPopulation), 1), (Code(Rich), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 24,
+        name: "Advanced Weapons",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 5),
+        base_price: 150_000,
+        purchase_dms: &[(HighTech, 2)],
+        sale_dms: &[(Code(Poor), 1), (AmberZone, 2), (RedZone, 4)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 25,
+        name: "Advanced Vehicles",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 5),
+        base_price: 180_000,
+        purchase_dms: &[(HighTech, 2)],
+        sale_dms: &[(Code(AsteroidBelt), 2), (Code(Rich), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 26,
+        name: "Biochemicals",
+        availability: &[Code(Agricultural), Code(WaterWorld)],
+        tons: (1, 5),
+        base_price: 50_000,
+        purchase_dms: &[(Code(Agricultural), 1), (Code(WaterWorld), 2)],
+        sale_dms: &[(Code(Industrial), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 31,
+        name: "Crystals & Gems",
+        availability: &[Code(AsteroidBelt), Code(Desert), Code(IceCapped)],
+        tons: (1, 5),
+        base_price: 20_000,
+        purchase_dms: &[
+            (Code(AsteroidBelt), 2),
+            (Code(Desert), 1),
+            (Code(IceCapped), 1),
+        ],
+        sale_dms: &[(Code(Industrial), 3), (Code(Rich), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 32,
+        name: "Cybernetics",
+        availability: &[HighTech],
+        tons: (1, 1),
+        base_price: 250_000,
+        purchase_dms: &[(HighTech, 1)],
+        sale_dms: &[
+            (Code(AsteroidBelt), 1),
+            (Code(IceCapped), 1),
+            (Code(Rich), 2),
+        ],
+        illegal: false,
+    },
+    TradeGood {
+        id: 33,
+        name: "Live Animals",
+        availability: &[Code(Agricultural), Code(Garden)],
+        tons: (1, 10),
+        base_price: 10_000,
+        purchase_dms: &[(Code(Agricultural), 2)],
+        sale_dms: &[(Code(LowPopulation), 3)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 34,
+        name: "Luxury Consumables",
+        availability: &[Code(Agricultural), Code(Garden), Code(WaterWorld)],
+        tons: (1, 10),
+        base_price: 20_000,
+        purchase_dms: &[(Code(Agricultural), 2), (Code(WaterWorld), 1)],
+        sale_dms: &[(Code(Rich), 2), (Code(HighPopulation), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 35,
+        name: "Luxury Goods",
+        availability: &[Code(HighPopulation)],
+        tons: (1, 1),
+        base_price: 200_000,
+        purchase_dms: &[(Code(HighPopulation), 1)],
+        sale_dms: &[(Code(Rich), 4)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 36,
+        name: "Medical Supplies",
+        availability: &[HighTech, Code(HighPopulation)],
+        tons: (1, 5),
+        base_price: 50_000,
+        purchase_dms: &[(HighTech, 2)],
+        sale_dms: &[(Code(Industrial), 2), (Code(Poor), 1), (Code(Rich), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 41,
+        name: "Petrochemicals",
+        availability: &[
+            Code(Desert),
+            Code(FluidOceans),
+            Code(IceCapped),
+            Code(WaterWorld),
+        ],
+        tons: (1, 10),
+        base_price: 10_000,
+        purchase_dms: &[(Code(Desert), 2)],
+        sale_dms: &[(Code(Industrial), 2), (Code(Agricultural), 1), (LowTech, 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 42,
+        name: "Pharmaceuticals",
+        availability: &[
+            Code(AsteroidBelt),
+            Code(Desert),
+            Code(HighPopulation),
+            Code(WaterWorld),
+        ],
+        tons: (1, 1),
+        base_price: 100_000,
+        purchase_dms: &[(Code(AsteroidBelt), 2), (Code(HighPopulation), 1)],
+        sale_dms: &[(Code(Rich), 2), (LowTech, 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 43,
+        name: "Polymers",
+        availability: &[Code(Industrial)],
+        tons: (1, 10),
+        base_price: 7_000,
+        purchase_dms: &[(Code(Industrial), 1)],
+        sale_dms: &[(Code(Rich), 2), (Code(NonIndustrial), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 44,
+        name: "Precious Metals",
+        availability: &[
+            Code(AsteroidBelt),
+            Code(Desert),
+            Code(IceCapped),
+            Code(FluidOceans),
+        ],
+        tons: (1, 1),
+        base_price: 50_000,
+        purchase_dms: &[
+            (Code(AsteroidBelt), 3),
+            (Code(Desert), 1),
+            (Code(IceCapped), 2),
+        ],
+        sale_dms: &[(Code(Rich), 3), (Code(Industrial), 2), (HighTech, 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 45,
+        name: "Radioactives",
+        availability: &[Code(AsteroidBelt), Code(Desert), Code(LowPopulation)],
+        tons: (1, 1),
+        base_price: 1_000_000,
+        purchase_dms: &[(Code(AsteroidBelt), 2), (Code(LowPopulation), 2)],
+        sale_dms: &[(Code(Industrial), 3), (HighTech, 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 46,
+        name: "Robots",
+        availability: &[Code(Industrial)],
+        tons: (1, 5),
+        base_price: 400_000,
+        purchase_dms: &[(Code(Industrial), 1)],
+        sale_dms: &[(Code(Agricultural), 2), (HighTech, 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 51,
+        name: "Spices",
+        availability: &[Code(Garden), Code(Desert), Code(WaterWorld)],
+        tons: (1, 10),
+        base_price: 6_000,
+        purchase_dms: &[(Code(Desert), 2)],
+        sale_dms: &[(Code(HighPopulation), 2), (Code(Rich), 3), (Code(Poor), 3)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 52,
+        name: "Textiles",
+        availability: &[Code(Agricultural), Code(NonIndustrial)],
+        tons: (1, 20),
+        base_price: 3_000,
+        purchase_dms: &[(Code(Agricultural), 7)],
+        sale_dms: &[(Code(HighPopulation), 3), (Code(NonAgricultural), 2)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 53,
+        name: "Uncommon Ore",
+        availability: &[Code(AsteroidBelt), Code(IceCapped)],
+        tons: (1, 20),
+        base_price: 5_000,
+        purchase_dms: &[(Code(AsteroidBelt), 4)],
+        sale_dms: &[(Code(Industrial), 3), (Code(NonIndustrial), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 54,
+        name: "Uncommon Raw Materials",
+        availability: &[Code(Agricultural), Code(Desert), Code(WaterWorld)],
+        tons: (1, 10),
+        base_price: 20_000,
+        purchase_dms: &[(Code(Agricultural), 2), (Code(WaterWorld), 1)],
+        sale_dms: &[(Code(Industrial), 2), (HighTech, 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 55,
+        name: "Wood",
+        availability: &[Code(Agricultural), Code(Garden)],
+        tons: (1, 20),
+        base_price: 1_000,
+        purchase_dms: &[(Code(Agricultural), 6)],
+        sale_dms: &[(Code(Rich), 2), (Code(Industrial), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 56,
+        name: "Vehicles",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 10),
+        base_price: 15_000,
+        purchase_dms: &[(Code(Industrial), 2), (HighTech, 1)],
+        sale_dms: &[(Code(NonIndustrial), 2), (Code(HighPopulation), 1)],
+        illegal: false,
+    },
+    TradeGood {
+        id: 63,
+        name: "Illegal Drugs",
+        availability: &[
+            Code(AsteroidBelt),
+            Code(Desert),
+            Code(HighPopulation),
+            Code(WaterWorld),
+        ],
+        tons: (1, 1),
+        base_price: 100_000,
+        purchase_dms: &[
+            (Code(AsteroidBelt), 1),
+            (Code(Desert), 1),
+            (Code(WaterWorld), 1),
+        ],
+        sale_dms: &[(Code(Rich), 6), (Code(HighPopulation), 6)],
+        illegal: true,
+    },
+    TradeGood {
+        id: 65,
+        name: "Illegal Weapons",
+        availability: &[Code(Industrial), HighTech],
+        tons: (1, 5),
+        base_price: 150_000,
+        purchase_dms: &[(HighTech, 2)],
+        sale_dms: &[(Code(Poor), 6), (AmberZone, 8), (RedZone, 10)],
+        illegal: true,
+    },
+];
+
+pub fn trade_good(id: i32) -> Option<&'static TradeGood> {
+    TRADE_GOODS.iter().find(|good| good.id == id)
+}
+
+/// Percentage of the base price paid when buying, by modified 3D roll.
+fn purchase_percent(roll: i32) -> i64 {
+    match roll {
+        ..=-3 => 300,
+        -2 => 250,
+        -1 => 200,
+        0 => 175,
+        1 => 150,
+        2 => 135,
+        3 => 125,
+        4 => 120,
+        5 => 115,
+        6 => 110,
+        7 => 105,
+        8 => 100,
+        9..=23 => 95 - 5 * (roll - 9) as i64,
+        _ => 20,
+    }
+}
+
+/// Percentage of the base price received when selling, by modified 3D roll.
+fn sale_percent(roll: i32) -> i64 {
+    match roll {
+        ..=-3 => 10,
+        -2 => 20,
+        -1 => 30,
+        0 => 40,
+        1..=10 => 40 + 5 * roll as i64,
+        11..=17 => 100 + 5 * (roll - 11) as i64,
+        18 => 140,
+        19 => 150,
+        20 => 160,
+        21 => 175,
+        22 => 200,
+        23 => 250,
+        24 => 300,
+        _ => 400,
+    }
+}
+
+/// Whoever haggles over the price, and what they charge for it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Broker {
+    pub skill: i32,
+    /// Share of the deal the broker keeps, in percent.
+    pub commission: i64,
+}
+
+impl Broker {
+    /// The traveller doing their own haggling.
+    pub fn own(skill: i32) -> Self {
+        Self {
+            skill,
+            commission: 0,
+        }
+    }
+
+    /// A broker hired at the starport, who charges more the better they are.
+    pub fn local(skill: i32) -> Self {
+        let skill = skill.clamp(1, 4);
+        let commission = match skill {
+            1 => 1,
+            2 => 2,
+            3 => 5,
+            _ => 10,
+        };
+        Self { skill, commission }
+    }
+}
+
+/// A cargo offered for sale at a market.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Lot {
+    pub good: &'static TradeGood,
+    pub tons: i32,
+}
+
+/// The speculative trade market of a system on a given day.
+///
+/// Every roll comes from the system seed and the date, so the same market
+/// on the same day always offers the same goods at the same prices.
+#[derive(Debug, Clone)]
+pub struct Market<'a> {
+    pub system: &'a System,
+    pub date: Date,
+}
+
+impl<'a> Market<'a> {
+    pub fn new(system: &'a System, date: Date) -> Self {
+        Self { system, date }
+    }
+
+    fn dice(&self, inputs: Vec<i64>) -> Dice {
+        self.system
+            .seed
+            .for_date(self.date)
+            .subseed(inputs)
+            .to_rng()
+    }
+
+    /// Goods on offer: everything the world produces plus a few odd lots
+    /// brought in by passing traders.
+    pub fn lots(&self) -> Vec<Lot> {
+        let mut rng = self.dice(vec![0]);
+        let population = self.system.mainworld.population;
+        let mut goods: Vec<&'static TradeGood> = TRADE_GOODS
+            .iter()
+            .filter(|good| !good.illegal && good.is_available(self.system))
+            .collect();
+        for _ in 0..rng.roll(1, 6, 0) {
+            let extra = &TRADE_GOODS[rng.roll(1, TRADE_GOODS.len() as i32, -1) as usize];
+            if !goods.contains(&extra) && (!extra.illegal || self.system.mainworld.law < 5) {
+                goods.push(extra)
+            }
+        }
+        goods.sort_by_key(|good| good.id);
+
+        goods
+            .into_iter()
+            .map(|good| {
+                let (dice, multiplier) = good.tons;
+                let scale = match population {
+                    ..=3 => -3,
+                    9.. => 3,
+                    _ => 0,
+                };
+                let tons = (rng.roll(dice, 6, 0) * multiplier + scale * multiplier).max(1);
+                Lot { good, tons }
+            })
+            .collect()
+    }
+
+    /// Credits per ton to buy a good here today, including the broker's cut.
+    pub fn purchase_price(&self, good: &TradeGood, broker: Broker) -> i64 {
+        let roll = self.dice(vec![1, good.id as i64]).roll(3, 6, 0)
+            + broker.skill
+            + good.purchase_dm(self.system);
+        good.base_price * purchase_percent(roll) * (100 + broker.commission) / 10_000
+    }
+
+    /// Credits per ton received for selling a good here today, after the broker's cut.
+    pub fn sale_price(&self, good: &TradeGood, broker: Broker) -> i64 {
+        let roll = self.dice(vec![2, good.id as i64]).roll(3, 6, 0)
+            + broker.skill
+            + good.sale_dm(self.system);
+        good.base_price * sale_percent(roll) * (100 - broker.commission) / 10_000
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> System {
+        let seed = Seed::new(String::from("test"));
+        generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc)
+    }
+
+    #[test]
+    fn test_goods_are_sorted() {
+        assert!(TRADE_GOODS.windows(2).all(|w| w[0].id < w[1].id));
+    }
+
+    #[test]
+    fn test_price_tables_are_monotonic() {
+        for roll in -5..30 {
+            assert!(purchase_percent(roll) >= purchase_percent(roll + 1));
+            assert!(sale_percent(roll) <= sale_percent(roll + 1));
+        }
+    }
+
+    #[test]
+    fn test_market_is_reproducible() {
+        let sys = setup();
+        let date = Date::new(1105, 100);
+        let good = trade_good(11).unwrap();
+        let (a, b) = (Market::new(&sys, date), Market::new(&sys, date));
+        assert_eq!(a.lots(), b.lots());
+        assert_eq!(
+            a.purchase_price(good, Broker::default()),
+            b.purchase_price(good, Broker::default())
+        );
+    }
+
+    #[test]
+    fn test_common_goods_always_available() {
+        let sys = setup();
+        let lots = Market::new(&sys, Date::new(1105, 1)).lots();
+        for id in 11..=16 {
+            assert!(lots.iter().any(|lot| lot.good.id == id));
+        }
+    }
+
+    #[test]
+    fn test_broker_helps_buyer() {
+        let sys = setup();
+        let market = Market::new(&sys, Date::new(1105, 1));
+        let good = trade_good(21).unwrap();
+        assert!(
+            market.purchase_price(good, Broker::own(3))
+                <= market.purchase_price(good, Broker::own(0))
+        );
+    }
+}
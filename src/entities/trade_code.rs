@@ -0,0 +1,484 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TradeCodeCategory {
+    Planetary,
+    Population,
+    Economic,
+    Climate,
+    Political,
+    Special,
+}
+
+impl Display for TradeCodeCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TradeCodeCategory::Planetary => "Planetary",
+            TradeCodeCategory::Population => "Population",
+            TradeCodeCategory::Economic => "Economic",
+            TradeCodeCategory::Climate => "Climate",
+            TradeCodeCategory::Political => "Political",
+            TradeCodeCategory::Special => "Special",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A T5 trade classification, listed in the order they appear in remarks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TradeCode {
+    AsteroidBelt,
+    Vacuum,
+    Desert,
+    FluidOceans,
+    Garden,
+    Hellworld,
+    IceCapped,
+    OceanWorld,
+    WaterWorld,
+    Satellite,
+    Locked,
+    Dieback,
+    Barren,
+    LowPopulation,
+    NonIndustrial,
+    PreHighPopulation,
+    HighPopulation,
+    PreAgricultural,
+    Agricultural,
+    NonAgricultural,
+    Prison,
+    PreIndustrial,
+    Industrial,
+    Poor,
+    PreRich,
+    Rich,
+    Frozen,
+    Hot,
+    Cold,
+    Tundra,
+    Tropic,
+    TwilightZone,
+    Reserve,
+    Colony,
+    MilitaryRule,
+    Forbidden,
+    Puzzle,
+    Dangerous,
+    Farming,
+    Mining,
+    PenalColony,
+    ResearchStation,
+    DataRepository,
+    AncientSite,
+    SubsectorCapital,
+    SectorCapital,
+    Capital,
+}
+
+impl TradeCode {
+    pub const ALL: [TradeCode; 47] = [
+        TradeCode::AsteroidBelt,
+        TradeCode::Vacuum,
+        TradeCode::Desert,
+        TradeCode::FluidOceans,
+        TradeCode::Garden,
+        TradeCode::Hellworld,
+        TradeCode::IceCapped,
+        TradeCode::OceanWorld,
+        TradeCode::WaterWorld,
+        TradeCode::Satellite,
+        TradeCode::Locked,
+        TradeCode::Dieback,
+        TradeCode::Barren,
+        TradeCode::LowPopulation,
+        TradeCode::NonIndustrial,
+        TradeCode::PreHighPopulation,
+        TradeCode::HighPopulation,
+        TradeCode::PreAgricultural,
+        TradeCode::Agricultural,
+        TradeCode::NonAgricultural,
+        TradeCode::Prison,
+        TradeCode::PreIndustrial,
+        TradeCode::Industrial,
+        TradeCode::Poor,
+        TradeCode::PreRich,
+        TradeCode::Rich,
+        TradeCode::Frozen,
+        TradeCode::Hot,
+        TradeCode::Cold,
+        TradeCode::Tundra,
+        TradeCode::Tropic,
+        TradeCode::TwilightZone,
+        TradeCode::Reserve,
+        TradeCode::Colony,
+        TradeCode::MilitaryRule,
+        TradeCode::Forbidden,
+        TradeCode::Puzzle,
+        TradeCode::Dangerous,
+        TradeCode::Farming,
+        TradeCode::Mining,
+        TradeCode::PenalColony,
+        TradeCode::ResearchStation,
+        TradeCode::DataRepository,
+        TradeCode::AncientSite,
+        TradeCode::SubsectorCapital,
+        TradeCode::SectorCapital,
+        TradeCode::Capital,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            TradeCode::AsteroidBelt => "As",
+            TradeCode::Vacuum => "Va",
+            TradeCode::Desert => "De",
+            TradeCode::FluidOceans => "Fl",
+            TradeCode::Garden => "Ga",
+            TradeCode::Hellworld => "He",
+            TradeCode::IceCapped => "Ic",
+            TradeCode::OceanWorld => "Oc",
+            TradeCode::WaterWorld => "Wa",
+            TradeCode::Satellite => "Sa",
+            TradeCode::Locked => "Lk",
+            TradeCode::Dieback => "Di",
+            TradeCode::Barren => "Ba",
+            TradeCode::LowPopulation => "Lo",
+            TradeCode::NonIndustrial => "Ni",
+            TradeCode::PreHighPopulation => "Ph",
+            TradeCode::HighPopulation => "Hi",
+            TradeCode::PreAgricultural => "Pa",
+            TradeCode::Agricultural => "Ag",
+            TradeCode::NonAgricultural => "Na",
+            TradeCode::Prison => "Px",
+            TradeCode::PreIndustrial => "Pi",
+            TradeCode::Industrial => "In",
+            TradeCode::Poor => "Po",
+            TradeCode::PreRich => "Pr",
+            TradeCode::Rich => "Ri",
+            TradeCode::Frozen => "Fr",
+            TradeCode::Hot => "Ho",
+            TradeCode::Cold => "Co",
+            TradeCode::Tundra => "Tu",
+            TradeCode::Tropic => "Tr",
+            TradeCode::TwilightZone => "Tz",
+            TradeCode::Reserve => "Re",
+            TradeCode::Colony => "Cy",
+            TradeCode::MilitaryRule => "Mr",
+            TradeCode::Forbidden => "Fo",
+            TradeCode::Puzzle => "Pz",
+            TradeCode::Dangerous => "Da",
+            TradeCode::Farming => "Fa",
+            TradeCode::Mining => "Mi",
+            TradeCode::PenalColony => "Pe",
+            TradeCode::ResearchStation => "Rs",
+            TradeCode::DataRepository => "Ab",
+            TradeCode::AncientSite => "An",
+            TradeCode::SubsectorCapital => "Cp",
+            TradeCode::SectorCapital => "Cs",
+            TradeCode::Capital => "Cx",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TradeCode::AsteroidBelt => "Asteroid Belt",
+            TradeCode::Vacuum => "Vacuum",
+            TradeCode::Desert => "Desert",
+            TradeCode::FluidOceans => "Fluid Oceans",
+            TradeCode::Garden => "Garden",
+            TradeCode::Hellworld => "Hellworld",
+            TradeCode::IceCapped => "Ice-Capped",
+            TradeCode::OceanWorld => "Ocean World",
+            TradeCode::WaterWorld => "Water World",
+            TradeCode::Satellite => "Satellite",
+            TradeCode::Locked => "Locked",
+            TradeCode::Dieback => "Dieback",
+            TradeCode::Barren => "Barren",
+            TradeCode::LowPopulation => "Low Population",
+            TradeCode::NonIndustrial => "Non-Industrial",
+            TradeCode::PreHighPopulation => "Pre-High Population",
+            TradeCode::HighPopulation => "High Population",
+            TradeCode::PreAgricultural => "Pre-Agricultural",
+            TradeCode::Agricultural => "Agricultural",
+            TradeCode::NonAgricultural => "Non-Agricultural",
+            TradeCode::Prison => "Prison, Exile Camp",
+            TradeCode::PreIndustrial => "Pre-Industrial",
+            TradeCode::Industrial => "Industrial",
+            TradeCode::Poor => "Poor",
+            TradeCode::PreRich => "Pre-Rich",
+            TradeCode::Rich => "Rich",
+            TradeCode::Frozen => "Frozen",
+            TradeCode::Hot => "Hot",
+            TradeCode::Cold => "Cold",
+            TradeCode::Tundra => "Tundra",
+            TradeCode::Tropic => "Tropic",
+            TradeCode::TwilightZone => "Twilight Zone",
+            TradeCode::Reserve => "Reserve",
+            TradeCode::Colony => "Colony",
+            TradeCode::MilitaryRule => "Military Rule",
+            TradeCode::Forbidden => "Forbidden",
+            TradeCode::Puzzle => "Puzzle",
+            TradeCode::Dangerous => "Dangerous",
+            TradeCode::Farming => "Farming",
+            TradeCode::Mining => "Mining",
+            TradeCode::PenalColony => "Penal Colony",
+            TradeCode::ResearchStation => "Research Station",
+            TradeCode::DataRepository => "Data Repository",
+            TradeCode::AncientSite => "Ancient Site",
+            TradeCode::SubsectorCapital => "Subsector Capital",
+            TradeCode::SectorCapital => "Sector Capital",
+            TradeCode::Capital => "Capital",
+        }
+    }
+
+    pub fn category(&self) -> TradeCodeCategory {
+        match self {
+            TradeCode::AsteroidBelt
+            | TradeCode::Vacuum
+            | TradeCode::Desert
+            | TradeCode::FluidOceans
+            | TradeCode::Garden
+            | TradeCode::Hellworld
+            | TradeCode::IceCapped
+            | TradeCode::OceanWorld
+            | TradeCode::WaterWorld
+            | TradeCode::Satellite
+            | TradeCode::Locked => TradeCodeCategory::Planetary,
+            TradeCode::Dieback
+            | TradeCode::Barren
+            | TradeCode::LowPopulation
+            | TradeCode::NonIndustrial
+            | TradeCode::PreHighPopulation
+            | TradeCode::HighPopulation => TradeCodeCategory::Population,
+            TradeCode::PreAgricultural
+            | TradeCode::Agricultural
+            | TradeCode::NonAgricultural
+            | TradeCode::Prison
+            | TradeCode::PreIndustrial
+            | TradeCode::Industrial
+            | TradeCode::Poor
+            | TradeCode::PreRich
+            | TradeCode::Rich
+            | TradeCode::Farming
+            | TradeCode::Mining
+            | TradeCode::PenalColony => TradeCodeCategory::Economic,
+            TradeCode::Frozen
+            | TradeCode::Hot
+            | TradeCode::Cold
+            | TradeCode::Tundra
+            | TradeCode::Tropic
+            | TradeCode::TwilightZone => TradeCodeCategory::Climate,
+            TradeCode::Reserve
+            | TradeCode::Colony
+            | TradeCode::MilitaryRule
+            | TradeCode::SubsectorCapital
+            | TradeCode::SectorCapital
+            | TradeCode::Capital => TradeCodeCategory::Political,
+            TradeCode::Forbidden
+            | TradeCode::Puzzle
+            | TradeCode::Dangerous
+            | TradeCode::ResearchStation
+            | TradeCode::DataRepository
+            | TradeCode::AncientSite => TradeCodeCategory::Special,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            TradeCode::AsteroidBelt => "A belt of planetoids with no single world.",
+            TradeCode::Vacuum => "No atmosphere; vacc suits are needed outdoors.",
+            TradeCode::Desert => "An atmosphere but no free standing water.",
+            TradeCode::FluidOceans => "Oceans of something other than water.",
+            TradeCode::Garden => "Pleasant for humans, with breathable air and open water.",
+            TradeCode::Hellworld => "Hostile air and little water on a sizeable world.",
+            TradeCode::IceCapped => "Water locked up in polar ice caps.",
+            TradeCode::OceanWorld => "A large world covered entirely by water.",
+            TradeCode::WaterWorld => "Almost no land above the waves.",
+            TradeCode::Satellite => "Orbits a larger world rather than the star.",
+            TradeCode::Locked => "A close satellite tidally locked to its parent.",
+            TradeCode::Dieback => "Once populated, now abandoned ruins.",
+            TradeCode::Barren => "Never settled and without a government.",
+            TradeCode::LowPopulation => "A few thousand inhabitants at most.",
+            TradeCode::NonIndustrial => "Too few people to support heavy industry.",
+            TradeCode::PreHighPopulation => "Approaching a billion inhabitants.",
+            TradeCode::HighPopulation => "Billions of inhabitants.",
+            TradeCode::PreAgricultural => "Farmland not yet fully exploited.",
+            TradeCode::Agricultural => "Exports food and other agricultural goods.",
+            TradeCode::NonAgricultural => "Must import most of its food.",
+            TradeCode::Prison => "Used to hold prisoners or exiles.",
+            TradeCode::PreIndustrial => "Developing towards an industrial economy.",
+            TradeCode::Industrial => "Heavy industry and manufactured exports.",
+            TradeCode::Poor => "Scarce resources and a struggling economy.",
+            TradeCode::PreRich => "A promising world on its way to prosperity.",
+            TradeCode::Rich => "Wealthy, with a strong demand for luxuries.",
+            TradeCode::Frozen => "Far outside the habitable zone and icy.",
+            TradeCode::Hot => "Closer to its star than the habitable zone.",
+            TradeCode::Cold => "Just beyond the habitable zone.",
+            TradeCode::Tundra => "A cold world with frozen plains.",
+            TradeCode::Tropic => "A hot world with lush equatorial belts.",
+            TradeCode::TwilightZone => "Only the band between day and night is temperate.",
+            TradeCode::Reserve => "Set aside for wildlife or native inhabitants.",
+            TradeCode::Colony => "Governed from another world.",
+            TradeCode::MilitaryRule => "Ruled by military force.",
+            TradeCode::Forbidden => "Interdicted; travel is prohibited.",
+            TradeCode::Puzzle => "A populous world under a travel advisory.",
+            TradeCode::Dangerous => "Visitors should take care.",
+            TradeCode::Farming => "A farming settlement supplying the mainworld.",
+            TradeCode::Mining => "A mining settlement supplying the mainworld's industry.",
+            TradeCode::PenalColony => "A penal settlement away from the mainworld.",
+            TradeCode::ResearchStation => "Hosts a research installation.",
+            TradeCode::DataRepository => "Hosts a major archive of knowledge.",
+            TradeCode::AncientSite => "Holds remains of an ancient civilisation.",
+            TradeCode::SubsectorCapital => "Seat of a subsector government.",
+            TradeCode::SectorCapital => "Seat of a sector government.",
+            TradeCode::Capital => "Seat of a polity's government.",
+        }
+    }
+
+    fn bit(&self) -> u64 {
+        1 << (*self as u64)
+    }
+}
+
+impl Display for TradeCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.code())
+    }
+}
+
+impl FromStr for TradeCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TradeCode::ALL
+            .into_iter()
+            .find(|code| code.code() == s)
+            .ok_or_else(|| format!("Unknown trade code '{s}'"))
+    }
+}
+
+/// A set of trade codes packed into a single word.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct TradeCodes(u64);
+
+impl TradeCodes {
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    /// Picks the trade codes out of a remarks column, skipping anything else in it.
+    pub fn from_remarks(remarks: &str) -> Self {
+        remarks
+            .split_whitespace()
+            .filter_map(|token| token.parse::<TradeCode>().ok())
+            .collect()
+    }
+
+    pub fn insert(&mut self, code: TradeCode) {
+        self.0 |= code.bit()
+    }
+
+    pub fn remove(&mut self, code: TradeCode) {
+        self.0 &= !code.bit()
+    }
+
+    pub fn contains(&self, code: TradeCode) -> bool {
+        self.0 & code.bit() != 0
+    }
+
+    pub fn union(&self, other: TradeCodes) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = TradeCode> + '_ {
+        TradeCode::ALL
+            .into_iter()
+            .filter(|code| self.contains(*code))
+    }
+
+    pub fn in_category(&self, category: TradeCodeCategory) -> impl Iterator<Item = TradeCode> + '_ {
+        self.iter().filter(move |code| code.category() == category)
+    }
+}
+
+impl FromIterator<TradeCode> for TradeCodes {
+    fn from_iter<T: IntoIterator<Item = TradeCode>>(iter: T) -> Self {
+        let mut codes = TradeCodes::new();
+        for code in iter {
+            codes.insert(code)
+        }
+        codes
+    }
+}
+
+impl Extend<TradeCode> for TradeCodes {
+    fn extend<T: IntoIterator<Item = TradeCode>>(&mut self, iter: T) {
+        for code in iter {
+            self.insert(code)
+        }
+    }
+}
+
+impl Display for TradeCodes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let codes = self
+            .iter()
+            .map(|code| code.code())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        f.pad(&codes)
+    }
+}
+
+impl FromStr for TradeCodes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace().map(|token| token.parse()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_codes_fit() {
+        assert!(TradeCode::ALL.len() <= 64);
+        for (i, code) in TradeCode::ALL.into_iter().enumerate() {
+            assert_eq!(code as usize, i)
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let remarks = "Ni Ag Ri Cx";
+        let codes: TradeCodes = remarks.parse().unwrap();
+        assert_eq!(codes.len(), 4);
+        assert!(codes.contains(TradeCode::Agricultural));
+        assert_eq!(codes.to_string(), remarks);
+    }
+
+    #[test]
+    fn test_remarks_skip_unknown() {
+        let codes = TradeCodes::from_remarks("Hi In (Aslan)7 Asla0");
+        assert_eq!(codes.to_string(), "Hi In");
+        assert!("Hi Xx".parse::<TradeCodes>().is_err());
+    }
+
+    #[test]
+    fn test_categories() {
+        let codes = TradeCodes::from_remarks("Ga Hi Ri Tu Cy Fo");
+        let economic: Vec<TradeCode> = codes.in_category(TradeCodeCategory::Economic).collect();
+        assert_eq!(economic, vec![TradeCode::Rich]);
+    }
+}
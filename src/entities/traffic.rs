@@ -0,0 +1,318 @@
+use std::fmt::Display;
+
+use crate::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Mission {
+    Merchant,
+    Patrol,
+    Pirate,
+    Scout,
+}
+
+impl Display for Mission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mission::Merchant => write!(f, "Merchant"),
+            Mission::Patrol => write!(f, "Patrol"),
+            Mission::Pirate => write!(f, "Pirate"),
+            Mission::Scout => write!(f, "Scout"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ShipType {
+    FreeTrader,
+    FarTrader,
+    SubsidisedMerchant,
+    Liner,
+    Miner,
+    SystemDefenceBoat,
+    PatrolCorvette,
+    Frigate,
+    Cruiser,
+    Corsair,
+    Raider,
+    ScoutCourier,
+    SurveyShip,
+}
+
+impl Display for ShipType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShipType::FreeTrader => write!(f, "Free Trader"),
+            ShipType::FarTrader => write!(f, "Far Trader"),
+            ShipType::SubsidisedMerchant => write!(f, "Subsidised Merchant"),
+            ShipType::Liner => write!(f, "Liner"),
+            ShipType::Miner => write!(f, "Miner"),
+            ShipType::SystemDefenceBoat => write!(f, "System Defence Boat"),
+            ShipType::PatrolCorvette => write!(f, "Patrol Corvette"),
+            ShipType::Frigate => write!(f, "Frigate"),
+            ShipType::Cruiser => write!(f, "Cruiser"),
+            ShipType::Corsair => write!(f, "Corsair"),
+            ShipType::Raider => write!(f, "Raider"),
+            ShipType::ScoutCourier => write!(f, "Scout/Courier"),
+            ShipType::SurveyShip => write!(f, "Survey Ship"),
+        }
+    }
+}
+
+/// How a ship's crew treats the travellers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Disposition {
+    Hostile,
+    Wary,
+    Neutral,
+    Friendly,
+}
+
+impl Display for Disposition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Disposition::Hostile => write!(f, "Hostile"),
+            Disposition::Wary => write!(f, "Wary"),
+            Disposition::Neutral => write!(f, "Neutral"),
+            Disposition::Friendly => write!(f, "Friendly"),
+        }
+    }
+}
+
+/// Where in the system the encounter happens.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterPlace {
+    Starport,
+    InSystem,
+}
+
+impl Display for EncounterPlace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncounterPlace::Starport => write!(f, "Starport"),
+            EncounterPlace::InSystem => write!(f, "In-system"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Encounter {
+    pub place: EncounterPlace,
+    pub ship: ShipType,
+    pub allegiance: String,
+    pub mission: Mission,
+    pub disposition: Disposition,
+}
+
+impl Display for Encounter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:9} {:19} {:4} {:8} {}",
+            self.place.to_string(),
+            self.ship.to_string(),
+            self.allegiance,
+            self.mission.to_string(),
+            self.disposition
+        )
+    }
+}
+
+fn pick<T: Copy>(rng: &mut Dice, weights: &[(T, i32)]) -> T {
+    let total: i32 = weights.iter().map(|(_, w)| (*w).max(0)).sum();
+    let mut roll = rng.roll(1, total, -1);
+    for (item, weight) in weights {
+        if roll < (*weight).max(0) {
+            return *item;
+        }
+        roll -= (*weight).max(0);
+    }
+    weights[0].0
+}
+
+/// Ships arriving at a system on a given day.
+///
+/// Built around `Extensions::expected_daily_ship_traffic` and rolled from the
+/// system seed for that date, so every day is reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Traffic {
+    pub date: Date,
+    pub arrivals: Vec<Encounter>,
+}
+
+impl Traffic {
+    pub fn new(sys: &System, date: Date) -> Self {
+        let mut rng = sys.seed.for_date(date).subseed(vec!["traffic"]).to_rng();
+        let world = &sys.mainworld;
+
+        let expected = sys.extensions.expected_daily_ship_traffic();
+        let count = match expected {
+            0 => i32::from(rng.roll(2, 6, 0) == 12),
+            _ => (expected * rng.roll(2, 6, 0) + 3) / 7,
+        };
+        // Travellers give zoned systems a wide berth
+        let count = match world.travel_zone {
+            TravelZone::Green => count,
+            TravelZone::Amber => (count + 1) / 2,
+            TravelZone::Red => (count + 3) / 4,
+        };
+
+        let arrivals = (0..count).map(|_| Self::encounter(&mut rng, sys)).collect();
+        Self { date, arrivals }
+    }
+
+    fn encounter(rng: &mut Dice, sys: &System) -> Encounter {
+        let world = &sys.mainworld;
+        let law = world.law;
+        let red = world.travel_zone == TravelZone::Red;
+        let amber = world.travel_zone == TravelZone::Amber;
+        let mission = pick(
+            rng,
+            &[
+                (Mission::Merchant, if red { 1 } else { 8 }),
+                (
+                    Mission::Patrol,
+                    1 + law / 3
+                        + 2 * i32::from(world.has_naval_base() || world.has_military_base())
+                        + 4 * i32::from(red),
+                ),
+                (
+                    Mission::Pirate,
+                    (4 - law).max(0)
+                        + 3 * i32::from(world.has_corsair_base())
+                        + 2 * i32::from(amber),
+                ),
+                (
+                    Mission::Scout,
+                    1 + 2 * i32::from(world.has_scout_base() || world.has_way_station()),
+                ),
+            ],
+        );
+
+        let big_port = world.port <= Starport::B;
+        let ship = match mission {
+            Mission::Merchant => pick(
+                rng,
+                &[
+                    (ShipType::FreeTrader, 6),
+                    (ShipType::FarTrader, 3),
+                    (ShipType::SubsidisedMerchant, if big_port { 3 } else { 1 }),
+                    (ShipType::Liner, if big_port { 2 } else { 0 }),
+                    (ShipType::Miner, sys.belts),
+                ],
+            ),
+            Mission::Patrol => pick(
+                rng,
+                &[
+                    (
+                        ShipType::SystemDefenceBoat,
+                        if world.tech >= 9 { 4 } else { 0 },
+                    ),
+                    (ShipType::PatrolCorvette, 4),
+                    (
+                        ShipType::Frigate,
+                        if world.has_naval_base() || world.has_military_base() {
+                            3
+                        } else {
+                            1
+                        },
+                    ),
+                    (ShipType::Cruiser, i32::from(world.has_naval_base())),
+                ],
+            ),
+            Mission::Pirate => pick(rng, &[(ShipType::Corsair, 3), (ShipType::Raider, 2)]),
+            Mission::Scout => pick(
+                rng,
+                &[(ShipType::ScoutCourier, 5), (ShipType::SurveyShip, 1)],
+            ),
+        };
+
+        let allegiance = match mission {
+            Mission::Pirate => String::from(NON_ALIGNED),
+            Mission::Patrol | Mission::Scout => sys.allegiance.clone(),
+            // Most merchants are local, the rest from anywhere
+            Mission::Merchant if rng.roll(1, 6, 0) <= 4 => sys.allegiance.clone(),
+            Mission::Merchant => String::from(NON_ALIGNED),
+        };
+
+        let place = match (mission, ship) {
+            (_, ShipType::Miner | ShipType::SystemDefenceBoat) => EncounterPlace::InSystem,
+            (Mission::Pirate, _) => EncounterPlace::InSystem,
+            _ if world.port == Starport::X => EncounterPlace::InSystem,
+            (Mission::Patrol, _) if rng.roll(1, 6, 0) <= 4 => EncounterPlace::InSystem,
+            _ if rng.roll(1, 6, 0) == 6 => EncounterPlace::InSystem,
+            _ => EncounterPlace::Starport,
+        };
+
+        let dm = match mission {
+            Mission::Pirate => -5,
+            Mission::Patrol => -law / 4,
+            Mission::Merchant => 1,
+            Mission::Scout => 0,
+        } - 2 * i32::from(red)
+            - i32::from(amber);
+        let disposition = match rng.roll(2, 6, dm) {
+            ..=3 => Disposition::Hostile,
+            4..=6 => Disposition::Wary,
+            7..=9 => Disposition::Neutral,
+            _ => Disposition::Friendly,
+        };
+
+        Encounter {
+            place,
+            ship,
+            allegiance,
+            mission,
+            disposition,
+        }
+    }
+}
+
+impl Display for Traffic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} arrivals", self.date, self.arrivals.len())?;
+        for encounter in &self.arrivals {
+            write!(f, "\n{encounter}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> System {
+        let seed = Seed::new(String::from("test"));
+        generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc)
+    }
+
+    #[test]
+    fn test_major_worlds_are_busy() {
+        let mut sys = setup();
+        sys.extensions.importance = 6;
+        sys.mainworld.travel_zone = TravelZone::Green;
+        for day in 1..=30 {
+            // Even the lowest 2D roll brings in two sevenths of the expected ships
+            assert!(Traffic::new(&sys, Date::new(1105, day)).arrivals.len() >= 29);
+        }
+    }
+
+    #[test]
+    fn test_traffic_is_reproducible() {
+        let sys = setup();
+        let date = Date::new(1105, 200);
+        assert_eq!(Traffic::new(&sys, date), Traffic::new(&sys, date));
+    }
+
+    #[test]
+    fn test_pirates_are_unaligned_and_in_system() {
+        let sys = setup();
+        let encounters = (1..=DAYS_PER_YEAR)
+            .flat_map(|day| Traffic::new(&sys, Date::new(1105, day)).arrivals)
+            .filter(|e| e.mission == Mission::Pirate)
+            .collect::<Vec<Encounter>>();
+        assert!(encounters
+            .iter()
+            .all(|e| e.allegiance == NON_ALIGNED && e.place == EncounterPlace::InSystem));
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 use super::to_ehex;
+use crate::prelude::*;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Base {
@@ -8,6 +9,24 @@ pub enum Base {
     Scout,
     WayStation,
     Depot,
+    /// A non-Imperial navy's base.
+    Military,
+    Corsair,
+    Embassy,
+}
+
+impl Base {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Base::Naval => "Naval base",
+            Base::Scout => "Scout base",
+            Base::WayStation => "Way station",
+            Base::Depot => "Naval depot",
+            Base::Military => "Military base",
+            Base::Corsair => "Corsair base",
+            Base::Embassy => "Embassy",
+        }
+    }
 }
 
 impl Display for Base {
@@ -17,12 +36,15 @@ impl Display for Base {
             Base::Scout => "S",
             Base::WayStation => "W",
             Base::Depot => "D",
+            Base::Military => "M",
+            Base::Corsair => "C",
+            Base::Embassy => "E",
         };
         write!(f, "{code:}")
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TravelZone {
     Green,
     Amber,
@@ -54,7 +76,7 @@ pub struct World {
     pub mainworld_type: MainWorldType,
     pub hz_variance: i32,
     pub orbit: i32,
-    pub port: String,
+    pub port: Starport,
     pub bases: Vec<Base>,
     pub size: i32,
     pub atmosphere: i32,
@@ -84,7 +106,24 @@ impl Display for World {
     }
 }
 
+/// The best jump drive a world can build at its tech level.
+pub fn jump_for_tech(tech: i32) -> i32 {
+    match tech {
+        ..=8 => 0,
+        9 | 10 => 1,
+        11 => 2,
+        12 => 3,
+        13 => 4,
+        14 => 5,
+        _ => 6,
+    }
+}
+
 impl World {
+    pub fn law_profile(&self) -> LawProfile {
+        LawProfile::new(self)
+    }
+
     pub fn bases_to_string(&self) -> String {
         self.bases
             .iter()
@@ -109,6 +148,18 @@ impl World {
         self.bases.contains(&Base::Depot)
     }
 
+    pub fn has_military_base(&self) -> bool {
+        self.bases.contains(&Base::Military)
+    }
+
+    pub fn has_corsair_base(&self) -> bool {
+        self.bases.contains(&Base::Corsair)
+    }
+
+    pub fn has_embassy(&self) -> bool {
+        self.bases.contains(&Base::Embassy)
+    }
+
     // Planetary Trade Codes
     pub fn is_asteroid_belt(&self) -> bool {
         self.size == 0 && self.atmosphere == 0 && self.hydrographics == 0
@@ -154,17 +205,11 @@ impl World {
     }
 
     pub fn is_satellite(&self) -> bool {
-        match self.mainworld_type {
-            MainWorldType::FarSatellite(_orbit) => true,
-            _ => false,
-        }
+        matches!(self.mainworld_type, MainWorldType::FarSatellite(_))
     }
 
     pub fn is_tidally_locked(&self) -> bool {
-        match self.mainworld_type {
-            MainWorldType::CloseSatellite(_orbit) => true,
-            _ => false,
-        }
+        matches!(self.mainworld_type, MainWorldType::CloseSatellite(_))
     }
 
     // Population Trade Codes
@@ -267,11 +312,37 @@ impl World {
             && (4..=9).contains(&self.atmosphere)
             && (3..=7).contains(&self.hydrographics)
     }
-    // Secondary Trade Codes TODO: Add NonMainWorld Codes
+    // Secondary Trade Codes
+    /// Only other worlds in the system farm; the mainworld would be Ag instead.
+    pub fn is_farming(&self) -> bool {
+        (4..=9).contains(&self.atmosphere)
+            && (4..=8).contains(&self.hydrographics)
+            && (2..=6).contains(&self.population)
+            && self.hz_variance == 0
+    }
+
+    /// Only other worlds in the system mine, supplying an industrial mainworld.
+    pub fn is_mining(&self, mainworld: &World) -> bool {
+        (2..=6).contains(&self.population) && mainworld.is_industrial()
+    }
+
+    pub fn is_military_rule(&self) -> bool {
+        self.government == 6 && self.law >= 9
+    }
+
+    /// The other world version of Px.
+    pub fn is_penal_colony(&self) -> bool {
+        [2, 3, 10, 11].contains(&self.atmosphere)
+            && (1..=5).contains(&self.hydrographics)
+            && (3..=6).contains(&self.population)
+            && self.government == 6
+            && (6..=9).contains(&self.law)
+    }
+
     pub fn is_reserve(&self) -> bool {
         self.population < 5 && self.government == 6 && [0, 4, 5].contains(&self.law)
     }
-    // Political Trade Codes TODO: Add capital logic
+    // Political Trade Codes, capitals depend on the polity and live on System
     pub fn is_colony(&self) -> bool {
         self.population > 4 && self.government == 6 && self.law < 4
     }
@@ -288,156 +359,160 @@ impl World {
         self.population < 7 && self.travel_zone == TravelZone::Amber
     }
 
-    pub fn trade_codes(&self) -> String {
-        let mut tc: Vec<&str> = vec![];
+    pub fn trade_codes(&self) -> TradeCodes {
+        let mut tc = TradeCodes::new();
         if self.is_asteroid_belt() {
-            tc.push("As")
+            tc.insert(TradeCode::AsteroidBelt)
         }
 
         if self.is_vacuum() {
-            tc.push("Va")
+            tc.insert(TradeCode::Vacuum)
         }
 
         if self.is_desert() {
-            tc.push("De")
+            tc.insert(TradeCode::Desert)
         }
 
         if self.is_fluid_oceans() {
-            tc.push("Fl")
+            tc.insert(TradeCode::FluidOceans)
         }
 
         if self.is_garden() {
-            tc.push("Ga")
+            tc.insert(TradeCode::Garden)
         }
 
         if self.is_hellworld() {
-            tc.push("He")
+            tc.insert(TradeCode::Hellworld)
         }
 
         if self.is_ice_capped() {
-            tc.push("Ic")
+            tc.insert(TradeCode::IceCapped)
         }
 
         if self.is_ocean_world() {
-            tc.push("Oc")
+            tc.insert(TradeCode::OceanWorld)
         }
 
         if self.is_water_world() {
-            tc.push("Wa")
+            tc.insert(TradeCode::WaterWorld)
         }
 
         if self.is_satellite() {
-            tc.push("Sa")
+            tc.insert(TradeCode::Satellite)
         }
 
         if self.is_tidally_locked() {
-            tc.push("Lk")
+            tc.insert(TradeCode::Locked)
         }
 
         if self.is_dieback() {
-            tc.push("Di")
+            tc.insert(TradeCode::Dieback)
         }
 
         if self.is_barren() {
-            tc.push("Ba")
+            tc.insert(TradeCode::Barren)
         }
 
         if self.is_low_pop() {
-            tc.push("Lo")
+            tc.insert(TradeCode::LowPopulation)
         }
 
         if self.is_nonindustrial() {
-            tc.push("Ni")
+            tc.insert(TradeCode::NonIndustrial)
         }
 
         if self.is_pre_high_pop() {
-            tc.push("Ph")
+            tc.insert(TradeCode::PreHighPopulation)
         }
 
         if self.is_high_pop() {
-            tc.push("Hi")
+            tc.insert(TradeCode::HighPopulation)
         }
 
         if self.is_preagricultural() {
-            tc.push("Pa")
+            tc.insert(TradeCode::PreAgricultural)
         }
 
         if self.is_agricultural() {
-            tc.push("Ag")
+            tc.insert(TradeCode::Agricultural)
         }
 
         if self.is_nonagricultural() {
-            tc.push("Na")
+            tc.insert(TradeCode::NonAgricultural)
         }
 
         if self.is_prison() {
-            tc.push("Px")
+            tc.insert(TradeCode::Prison)
         }
 
         if self.is_preindustrial() {
-            tc.push("Pi")
+            tc.insert(TradeCode::PreIndustrial)
         }
 
         if self.is_industrial() {
-            tc.push("In")
+            tc.insert(TradeCode::Industrial)
         }
 
         if self.is_poor() {
-            tc.push("Po")
+            tc.insert(TradeCode::Poor)
         }
 
         if self.is_pre_rich() {
-            tc.push("Pr")
+            tc.insert(TradeCode::PreRich)
         }
 
         if self.is_rich() {
-            tc.push("Ri")
+            tc.insert(TradeCode::Rich)
         }
 
         if self.is_frozen() {
-            tc.push("Fr")
+            tc.insert(TradeCode::Frozen)
         }
 
         if self.is_hot() {
-            tc.push("Ho")
+            tc.insert(TradeCode::Hot)
         }
 
         if self.is_cold() {
-            tc.push("Co")
+            tc.insert(TradeCode::Cold)
         }
 
         if self.is_tundra() {
-            tc.push("Tu")
+            tc.insert(TradeCode::Tundra)
         }
 
         if self.is_tropic() {
-            tc.push("Tr")
+            tc.insert(TradeCode::Tropic)
         }
 
         if self.is_twilight_zone() {
-            tc.push("Tz")
+            tc.insert(TradeCode::TwilightZone)
         }
 
         if self.is_reserve() {
-            tc.push("Re")
+            tc.insert(TradeCode::Reserve)
         }
 
         if self.is_colony() {
-            tc.push("Cy")
+            tc.insert(TradeCode::Colony)
+        }
+
+        if self.is_military_rule() {
+            tc.insert(TradeCode::MilitaryRule)
         }
 
         if self.is_forbiddin() {
-            tc.push("Fo")
+            tc.insert(TradeCode::Forbidden)
         }
 
         if self.is_puzzle() {
-            tc.push("Pz")
+            tc.insert(TradeCode::Puzzle)
         }
 
         if self.is_dangerous() {
-            tc.push("Da")
+            tc.insert(TradeCode::Dangerous)
         }
 
-        String::from(tc.join(" "))
+        tc
     }
 }
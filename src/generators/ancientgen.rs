@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+/// Rolls for remains in a star system. Major sites come from the system's own
+/// dice; everything about the site comes from a subseed so nothing else shifts.
+pub fn generate_ancient_site(seed: &Seed, major: bool) -> Option<AncientSite> {
+    let mut rng = seed.subseed(vec!["ancient"]).to_rng();
+    // Lesser remains are commoner than sites worth a trade code
+    if !major && (rng.roll(2, 6, 0) < 12 || rng.roll(1, 6, 0) < 4) {
+        return None;
+    }
+    let kind = match rng.roll(1, 6, 0) {
+        1..=3 => AncientSiteKind::Ruins,
+        4 => AncientSiteKind::Artefact,
+        5 => AncientSiteKind::Derelict,
+        _ => AncientSiteKind::Installation,
+    };
+    Some(AncientSite::new(&mut rng, kind, major))
+}
@@ -0,0 +1,134 @@
+use crate::prelude::*;
+
+/// X-boats and scout couriers make jump-4 hops between scout bases.
+const SCOUT_ROUTE_JUMP: i32 = 4;
+
+fn add_base(world: &mut World, base: Base) {
+    if !world.bases.contains(&base) {
+        world.bases.push(base)
+    }
+}
+
+/// Places way stations at junctions of the scout routes. Way stations add to
+/// importance, so this runs before polities and capitals are chosen by it.
+pub fn generate_way_stations(systems: &mut [System]) {
+    let scout_bases: Vec<Coordinate> = systems
+        .iter()
+        .filter(|s| s.mainworld.has_scout_base())
+        .map(|s| s.location)
+        .collect();
+    for sys in systems.iter_mut() {
+        let links = scout_bases
+            .iter()
+            .filter(|hex| **hex != sys.location && hex.distance(&sys.location) <= SCOUT_ROUTE_JUMP)
+            .count();
+        // Route junctions with a decent port service the couriers
+        if sys.mainworld.has_scout_base() && links >= 2 && sys.mainworld.port <= Starport::B {
+            add_base(&mut sys.mainworld, Base::WayStation);
+            sys.extensions.refresh_importance(&sys.mainworld);
+        }
+    }
+}
+
+/// Places the bases that depend on polities: depots, non-Imperial military
+/// bases, corsair dens and embassies.
+pub fn generate_bases(seed: &Seed, systems: &mut [System], polities: &[Polity]) {
+    let mut rng = seed.subseed(vec!["bases"]).to_rng();
+
+    for polity in polities {
+        // Only the Imperium calls its navy's bases naval bases
+        if polity.kind != PolityKind::Imperium {
+            for sys in systems.iter_mut().filter(|s| s.allegiance == polity.code) {
+                if sys.mainworld.has_naval_base() {
+                    sys.mainworld.bases.retain(|b| *b != Base::Naval);
+                    add_base(&mut sys.mainworld, Base::Military);
+                }
+            }
+        }
+
+        // One depot per polity, at its best supplied naval station
+        let depot = systems
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| {
+                s.allegiance == polity.code
+                    && (s.mainworld.has_naval_base() || s.mainworld.has_military_base())
+            })
+            .min_by_key(|(_, s)| {
+                (
+                    s.mainworld.port,
+                    -s.mainworld.tech,
+                    -s.extensions.importance,
+                )
+            })
+            .map(|(i, _)| i);
+        if let Some(i) = depot {
+            add_base(&mut systems[i].mainworld, Base::Depot);
+        }
+    }
+
+    for sys in systems.iter_mut() {
+        let lawless = sys.mainworld.law <= 2;
+        let remote = sys.mainworld.port >= Starport::D;
+        let corsair_dm = lawless as i32 + remote as i32 + !sys.is_aligned() as i32;
+        if sys.mainworld.population > 0 && rng.roll(2, 6, corsair_dm) >= 13 {
+            add_base(&mut sys.mainworld, Base::Corsair);
+        }
+    }
+
+    // Capitals host embassies from their neighbours
+    if polities.len() > 1 {
+        for sys in systems
+            .iter_mut()
+            .filter(|s| s.capital == Some(Capital::Polity))
+        {
+            add_base(&mut sys.mainworld, Base::Embassy);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Sector {
+        let seed = Seed::new(String::from("test"));
+        generate_sector(seed, Coordinate::new(0, 0), 32, 40, &Density::Dense)
+    }
+
+    #[test]
+    fn test_one_depot_per_polity() {
+        let sector = setup();
+        for polity in &sector.polities {
+            let depots = sector
+                .members(&polity.code)
+                .filter(|sys| sys.mainworld.has_depot())
+                .count();
+            assert!(depots <= 1);
+        }
+    }
+
+    #[test]
+    fn test_way_stations_count_towards_importance() {
+        let sector = setup();
+        let mut sys = sector.systems[0].clone();
+        sys.mainworld.population = 6;
+        sys.mainworld.bases.retain(|base| *base != Base::WayStation);
+        sys.extensions.refresh_importance(&sys.mainworld);
+        let before = sys.extensions;
+        sys.mainworld.bases.push(Base::WayStation);
+        sys.extensions.refresh_importance(&sys.mainworld);
+        assert_eq!(sys.extensions.importance, before.importance + 1);
+        assert_eq!(sys.extensions.acceptance, 6 + sys.extensions.importance);
+    }
+
+    #[test]
+    fn test_naval_bases_are_imperial() {
+        let sector = setup();
+        for sys in sector.systems.iter().filter(|s| s.is_aligned()) {
+            let imperial = PolityKind::from_code(&sys.allegiance) == Some(PolityKind::Imperium);
+            assert!(!sys.mainworld.has_naval_base() || imperial);
+            assert!(!sys.mainworld.has_military_base() || !imperial);
+        }
+    }
+}
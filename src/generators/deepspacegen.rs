@@ -0,0 +1,120 @@
+use crate::prelude::*;
+
+/// Rolls for anything in a hex with no star system.
+///
+/// Stations and depots are tended by whoever owns the nearest system, so they
+/// are only kept when an aligned system lies close enough to supply them.
+pub fn generate_deep_space(seed: &Seed, location: Coordinate, systems: &[System]) -> DeepSpace {
+    let mut rng = seed.subseed(vec!["deep space"]).to_rng();
+    let nearest_owner = |range: i32| {
+        systems
+            .iter()
+            .filter(|sys| sys.is_aligned() && sys.location.distance(&location) <= range)
+            .min_by_key(|sys| sys.location.distance(&location))
+            .map(|sys| sys.allegiance.clone())
+    };
+
+    let mut features = vec![];
+    match rng.roll(3, 6, 0) {
+        3 | 4 => features.push(DeepSpaceFeature::Nebula),
+        5 => features.push(DeepSpaceFeature::BrownDwarf),
+        6 => features.push(DeepSpaceFeature::RoguePlanet {
+            size: rng.roll(2, 6, -2).max(1),
+            icy: rng.roll(1, 6, 0) >= 3,
+        }),
+        16 => features.push(DeepSpaceFeature::CometCloud),
+        17 => features.push(DeepSpaceFeature::Station {
+            allegiance: nearest_owner(3).unwrap_or_else(|| String::from(NON_ALIGNED)),
+        }),
+        18 => {
+            if let Some(allegiance) = nearest_owner(2) {
+                features.push(DeepSpaceFeature::RefuellingDepot { allegiance })
+            }
+        }
+        _ => (),
+    }
+
+    let ancient_site = match rng.roll(3, 6, 0) {
+        18 => Some(AncientSite::new(&mut rng, AncientSiteKind::Artefact, false)),
+        17 => Some(AncientSite::new(&mut rng, AncientSiteKind::Derelict, false)),
+        _ => None,
+    };
+
+    DeepSpace {
+        location,
+        features,
+        ancient_site,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Sector {
+        let seed = Seed::new(String::from("test"));
+        generate_sector(seed, Coordinate::new(0, 0), 32, 40, &Density::Sparse)
+    }
+
+    #[test]
+    fn test_depots_belong_to_nearby_polities() {
+        let sector = setup();
+        for hex in &sector.deep_space {
+            for feature in &hex.features {
+                if let DeepSpaceFeature::RefuellingDepot { allegiance } = feature {
+                    assert!(sector
+                        .members(allegiance)
+                        .any(|sys| sys.location.distance(&hex.location) <= 2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_depot_bridges_a_gap() {
+        let mut sector = setup();
+        let mut a = sector.systems[0].clone();
+        let mut b = sector.systems[1].clone();
+        a.location = Coordinate::new(1, 1);
+        b.location = Coordinate::new(1, 5);
+        sector.systems = vec![a, b];
+        sector.deep_space = vec![];
+        let (from, to) = (Coordinate::new(1, 1), Coordinate::new(1, 5));
+        assert_eq!(sector.jump_route(from, to, 2), None);
+
+        sector.deep_space.push(DeepSpace {
+            location: Coordinate::new(1, 3),
+            features: vec![DeepSpaceFeature::RefuellingDepot {
+                allegiance: String::from(NON_ALIGNED),
+            }],
+            ancient_site: None,
+        });
+        assert_eq!(
+            sector.jump_route(from, to, 2),
+            Some(vec![from, Coordinate::new(1, 3), to])
+        );
+    }
+
+    #[test]
+    fn test_dry_systems_are_not_waypoints() {
+        let mut sector = setup();
+        sector.deep_space = vec![];
+        let mut stop = sector.systems[0].clone();
+        stop.location = Coordinate::new(1, 3);
+        stop.mainworld.port = Starport::X;
+        stop.mainworld.hydrographics = 0;
+        stop.gas_giants = 0;
+        let (from, to) = (Coordinate::new(1, 1), Coordinate::new(1, 5));
+        let mut ends = sector.systems[1].clone();
+        ends.location = from;
+        sector.systems = vec![ends, stop.clone()];
+        assert_eq!(sector.jump_route(from, to, 2), None);
+
+        stop.gas_giants = 1;
+        sector.systems[1] = stop;
+        assert_eq!(
+            sector.jump_route(from, to, 2),
+            Some(vec![from, Coordinate::new(1, 3), to])
+        );
+    }
+}
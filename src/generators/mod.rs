@@ -4,6 +4,16 @@ mod worldgen;
 use worldgen::*;
 mod stargen;
 use stargen::*;
+mod politygen;
+pub use politygen::*;
+mod basegen;
+pub use basegen::*;
+mod sophontgen;
+pub use sophontgen::*;
+mod ancientgen;
+pub use ancientgen::*;
+mod deepspacegen;
+pub use deepspacegen::*;
 
 fn habitable_zone_variance(flux: i32) -> i32 {
     match flux {
@@ -15,12 +25,12 @@ fn habitable_zone_variance(flux: i32) -> i32 {
     }
 }
 
-pub fn generate_system(seed: Seed, location: Coordinate) -> System {
+pub fn generate_system(seed: Seed, location: Coordinate, population: StellarPopulation) -> System {
     let mut rng = seed.to_rng();
-    let stars = generate_stars(&mut rng);
+    let stars = generate_stars(&mut rng, population.spectral_dm());
     let hz_variance = habitable_zone_variance(rng.flux(0));
     let habitable_zone = stars[0].habitable_zone_orbit();
-    let mainworld = generate_mainworld(&mut rng, hz_variance, habitable_zone);
+    let mut mainworld = generate_mainworld(&mut rng, hz_variance, habitable_zone);
 
     let belts = rng.roll(1, 6, -3).max(0);
     let gas_giants = ((rng.roll(2, 6, 0) as f32 / 2.0) - 2.0).round().max(0.0) as i32;
@@ -28,32 +38,74 @@ pub fn generate_system(seed: Seed, location: Coordinate) -> System {
 
     let extensions = Extensions::new(&mut rng, &mainworld, gas_giants + belts);
 
+    let major_site = rng.roll(2, 6, 0) == 12 && rng.roll(1, 6, 0) == 6;
+    let ancient_site = generate_ancient_site(&seed, major_site);
+    let secondary_worlds = (0..(worlds - 1 - gas_giants - belts).max(0))
+        .map(|_| generate_secondary_world(&mut rng, &mainworld, habitable_zone))
+        .collect();
+
+    // Dangerous remains warrant a travel zone of their own
+    if let Some(site) = &ancient_site {
+        mainworld.travel_zone = mainworld.travel_zone.clone().max(site.travel_zone());
+    }
+
     System {
+        seed,
         location,
         name: String::from("Anonymous"),
         stars,
         extensions,
         mainworld,
+        secondary_worlds,
         worlds,
         belts,
         gas_giants,
-        allegiance: String::from("--"),
+        allegiance: String::from(NON_ALIGNED),
+        capital: None,
+        ancient_site,
+        sophonts: vec![],
     }
 }
 
-pub fn generate_sector(seed: Seed, row_count: u32, col_count: u32) -> Sector {
+pub fn generate_sector(
+    seed: Seed,
+    sector: Coordinate,
+    row_count: u32,
+    col_count: u32,
+    density: &dyn DensityMap,
+) -> Sector {
     let mut rng = seed.to_rng();
     let mut systems = vec![];
+    let mut empty_hexes = vec![];
 
     for row in 1..row_count + 1 {
         for col in 1..col_count + 1 {
-            if rng.roll(1, 6, 0) > 4 {
-                let coordinate = Coordinate::new(row as i32, col as i32);
+            let coordinate = Coordinate::new(row as i32, col as i32);
+            if density.density(sector, coordinate).system_present(&mut rng) {
                 let subseed = seed.subseed(vec![coordinate]);
-                systems.push(generate_system(subseed, coordinate))
+                let population = density.population(sector, coordinate);
+                systems.push(generate_system(subseed, coordinate, population))
             } else {
+                empty_hexes.push(coordinate)
             }
         }
     }
-    Sector { seed, systems }
+    generate_way_stations(&mut systems);
+    let mut polities = generate_polities(&seed, &mut systems);
+    designate_capitals(&mut systems, &mut polities);
+    generate_bases(&seed, &mut systems, &polities);
+    let sophonts = generate_sophonts(&seed, &mut systems);
+    let deep_space = empty_hexes
+        .into_iter()
+        .map(|hex| generate_deep_space(&seed.subseed(vec![hex]), hex, &systems))
+        .filter(|hex| !hex.is_empty())
+        .collect();
+    Sector {
+        seed,
+        location: sector,
+        systems,
+        polities,
+        sophonts,
+        deep_space,
+    }
 }
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+
+use crate::prelude::*;
+
+const NAME_PREFIXES: [&str; 12] = [
+    "Ka", "Vel", "Dra", "Or", "Sul", "Ten", "Mar", "Ast", "Zho", "Ili", "Ber", "Quen",
+];
+const NAME_SUFFIXES: [&str; 12] = [
+    "ran", "eth", "ova", "is", "adan", "umar", "ith", "oris", "el", "ane", "ost", "ura",
+];
+
+/// Systems at least this far apart may both found a polity.
+const MIN_ORIGIN_SPACING: i32 = 10;
+
+fn is_polity_origin(world: &World, extensions: &Extensions) -> bool {
+    extensions.importance >= 2 && world.tech >= 9 && world.population >= 6
+}
+
+/// Number of jumps out from its origin a polity keeps expanding.
+fn reach(world: &World, extensions: &Extensions) -> i32 {
+    (extensions.importance + (world.tech - 9) / 3).clamp(1, 5)
+}
+
+fn polity_name<R: Rollable>(rng: &mut R) -> String {
+    let prefix = NAME_PREFIXES[rng.roll(1, 12, -1) as usize];
+    let suffix = NAME_SUFFIXES[rng.roll(1, 12, -1) as usize];
+    format!("{prefix}{suffix}")
+}
+
+fn allegiance_code(kind: PolityKind, name: &str, taken: &HashSet<String>) -> String {
+    let mut letters = name.chars();
+    let first = letters.next().unwrap_or('X');
+    letters
+        .map(|c| format!("{}{first}{c}", kind.prefix()))
+        .chain((b'a'..=b'z').map(|c| format!("{}{first}{}", kind.prefix(), c as char)))
+        .find(|code| !taken.contains(code) && code != NON_ALIGNED)
+        .unwrap_or_else(|| format!("{}??", kind.prefix()))
+}
+
+/// Founds polities on important, high tech worlds and grows them across
+/// jump routes until they run out of reach or meet a neighbour.
+pub fn generate_polities(seed: &Seed, systems: &mut [System]) -> Vec<Polity> {
+    let mut rng = seed.subseed(vec!["polities"]).to_rng();
+
+    let mut candidates: Vec<usize> = (0..systems.len())
+        .filter(|&i| is_polity_origin(&systems[i].mainworld, &systems[i].extensions))
+        .collect();
+    candidates.sort_by_key(|&i| {
+        let sys = &systems[i];
+        (-sys.extensions.importance, -sys.mainworld.tech)
+    });
+
+    let mut polities: Vec<Polity> = vec![];
+    let mut reaches: Vec<i32> = vec![];
+    let mut owners: Vec<Option<usize>> = vec![None; systems.len()];
+    let mut taken: HashSet<String> = HashSet::new();
+
+    for i in candidates {
+        let sys = &systems[i];
+        let crowded = polities
+            .iter()
+            .any(|p| p.origin.distance(&sys.location) < MIN_ORIGIN_SPACING);
+        if crowded || rng.roll(2, 6, sys.extensions.importance) < 11 {
+            continue;
+        }
+
+        let kind = PolityKind::ALL[rng.roll(1, 6, -1) as usize];
+        let name = polity_name(&mut rng);
+        let code = allegiance_code(kind, &name, &taken);
+        taken.insert(code.clone());
+
+        owners[i] = Some(polities.len());
+        reaches.push(reach(&sys.mainworld, &sys.extensions));
+        polities.push(Polity {
+            code,
+            name,
+            kind,
+            origin: sys.location,
+            capital: sys.location,
+            jump: jump_for_tech(sys.mainworld.tech).min(2),
+            nobility: NobilityRules::for_kind(kind),
+        });
+    }
+
+    // Some systems hold out against every neighbour and stay independent
+    let holdouts: Vec<bool> = (0..systems.len()).map(|_| rng.roll(2, 6, 0) == 2).collect();
+
+    let max_reach = reaches.iter().copied().max().unwrap_or(0);
+    for step in 1..=max_reach {
+        let claimed = owners.clone();
+        for (p, polity) in polities.iter().enumerate() {
+            if step > reaches[p] {
+                continue;
+            }
+            let frontier: Vec<Coordinate> = claimed
+                .iter()
+                .enumerate()
+                .filter(|(_, owner)| **owner == Some(p))
+                .map(|(i, _)| systems[i].location)
+                .collect();
+
+            for (i, sys) in systems.iter().enumerate() {
+                if owners[i].is_some() || holdouts[i] {
+                    continue;
+                }
+                if frontier
+                    .iter()
+                    .any(|hex| hex.distance(&sys.location) <= polity.jump)
+                {
+                    owners[i] = Some(p);
+                }
+            }
+        }
+    }
+
+    for (sys, owner) in systems.iter_mut().zip(owners) {
+        sys.allegiance = match owner {
+            Some(p) => polities[p].code.clone(),
+            None => String::from(NON_ALIGNED),
+        };
+    }
+
+    polities
+}
+
+/// Most suitable seat of government first.
+fn capital_rank(sys: &System) -> (i32, i32, i32) {
+    (
+        -sys.extensions.importance,
+        -sys.mainworld.population,
+        -sys.mainworld.tech,
+    )
+}
+
+/// Picks out the administrative seats of each polity.
+///
+/// Each polity's best world becomes its capital (Cx). The dominant polity in
+/// the sector seats its sector government (Cs) on its next best world, and
+/// every polity with two or more worlds in a subsector gets a subsector
+/// capital (Cp) there.
+pub fn designate_capitals(systems: &mut [System], polities: &mut [Polity]) {
+    let mut ranked: Vec<usize> = (0..systems.len())
+        .filter(|&i| systems[i].is_aligned() && systems[i].mainworld.population > 0)
+        .collect();
+    ranked.sort_by_key(|&i| capital_rank(&systems[i]));
+
+    for polity in polities.iter_mut() {
+        if let Some(&i) = ranked
+            .iter()
+            .find(|&&i| systems[i].allegiance == polity.code)
+        {
+            systems[i].capital = Some(Capital::Polity);
+            polity.capital = systems[i].location;
+        }
+    }
+
+    let dominant = polities
+        .iter()
+        .max_by_key(|p| systems.iter().filter(|s| s.allegiance == p.code).count());
+    if let Some(polity) = dominant {
+        if let Some(&i) = ranked
+            .iter()
+            .find(|&&i| systems[i].allegiance == polity.code && !systems[i].is_capital())
+        {
+            systems[i].capital = Some(Capital::Sector);
+        }
+    }
+
+    for polity in polities.iter() {
+        for subsector in 'A'..='P' {
+            let members: Vec<usize> = ranked
+                .iter()
+                .copied()
+                .filter(|&i| {
+                    systems[i].allegiance == polity.code
+                        && systems[i].location.subsector() == subsector
+                })
+                .collect();
+            if members.len() < 2 || members.iter().any(|&i| systems[i].is_capital()) {
+                continue;
+            }
+            systems[members[0]].capital = Some(Capital::Subsector);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jump_for_tech() {
+        assert_eq!(jump_for_tech(8), 0);
+        assert_eq!(jump_for_tech(9), 1);
+        assert_eq!(jump_for_tech(12), 3);
+        assert_eq!(jump_for_tech(20), 6);
+    }
+
+    #[test]
+    fn test_allegiance_codes_are_unique() {
+        let mut taken = HashSet::new();
+        for _ in 0..4 {
+            let code = allegiance_code(PolityKind::League, "Kaeth", &taken);
+            assert!(code.starts_with("LgK"));
+            assert!(taken.insert(code));
+        }
+    }
+
+    #[test]
+    fn test_one_capital_per_polity() {
+        let seed = Seed::new(String::from("test"));
+        let sector = generate_sector(seed, Coordinate::new(0, 0), 32, 40, &Density::Dense);
+        for polity in &sector.polities {
+            let capitals: Vec<&System> = sector
+                .members(&polity.code)
+                .filter(|sys| sys.capital == Some(Capital::Polity))
+                .collect();
+            assert_eq!(capitals.len(), 1);
+            assert_eq!(capitals[0].location, polity.capital);
+        }
+    }
+
+    #[test]
+    fn test_polities_are_contiguous() {
+        let seed = Seed::new(String::from("test"));
+        let sector = generate_sector(seed, Coordinate::new(0, 0), 32, 40, &Density::Dense);
+        for polity in &sector.polities {
+            let members: Vec<&System> = sector.members(&polity.code).collect();
+            assert!(members.iter().any(|sys| sys.location == polity.origin));
+            for sys in &members {
+                assert!(
+                    members.iter().any(|other| other.location != sys.location
+                        && other.location.distance(&sys.location) <= polity.jump)
+                        || members.len() == 1
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashSet;
+
+use crate::prelude::*;
+
+const NAME_PREFIXES: [&str; 12] = [
+    "Ch", "Gh", "Xa", "Ll", "Sk", "Vr", "Tz", "Hr", "Qu", "Nn", "Kw", "Ul",
+];
+const NAME_MIDDLES: [&str; 6] = ["ir", "aa", "uu", "ek", "oth", "ae"];
+const NAME_SUFFIXES: [&str; 6] = ["ani", "ri", "xi", "ont", "ae", "esh"];
+
+fn sophont_name<R: Rollable>(rng: &mut R) -> String {
+    let prefix = NAME_PREFIXES[rng.roll(1, 12, -1) as usize];
+    let middle = NAME_MIDDLES[rng.roll(1, 6, -1) as usize];
+    let suffix = NAME_SUFFIXES[rng.roll(1, 6, -1) as usize];
+    format!("{prefix}{middle}{suffix}")
+}
+
+/// The first four letters of the name, changing the last until it is unique.
+fn sophont_code(name: &str, taken: &HashSet<String>) -> String {
+    let stem: String = name.chars().take(3).collect();
+    name.chars()
+        .skip(3)
+        .chain('a'..='z')
+        .map(|c| format!("{stem}{c}"))
+        .find(|code| !taken.contains(code))
+        .unwrap_or_else(|| format!("{stem}?"))
+}
+
+fn traits<R: Rollable>(rng: &mut R, world: &World) -> SophontTraits {
+    let body = match rng.roll(2, 6, 0) {
+        _ if world.hydrographics >= 9 => BodyPlan::Aquatic,
+        2 => BodyPlan::Radial,
+        3 | 4 => BodyPlan::Hexapod,
+        5..=8 => BodyPlan::Humanoid,
+        9 | 10 => BodyPlan::Quadruped,
+        11 => BodyPlan::Avian,
+        _ => BodyPlan::Serpentine,
+    };
+    // Heavy worlds breed small sophonts
+    let size = match rng.roll(2, 6, 7 - world.size) {
+        ..=5 => SophontSize::Small,
+        6..=9 => SophontSize::Medium,
+        _ => SophontSize::Large,
+    };
+    let diet = match rng.roll(1, 6, 0) {
+        1 | 2 => Diet::Herbivore,
+        3 | 4 => Diet::Omnivore,
+        5 => Diet::Carnivore,
+        _ => Diet::Scavenger,
+    };
+    let social = match rng.roll(2, 6, 0) {
+        2 => SocialStructure::Hive,
+        3..=5 => SocialStructure::Solitary,
+        6..=9 => SocialStructure::Familial,
+        _ => SocialStructure::Tribal,
+    };
+    SophontTraits {
+        body,
+        size,
+        diet,
+        social,
+    }
+}
+
+/// Chance in 2D that a biosphere has produced a sophont.
+fn homeworld_target(complexity: LifeComplexity) -> i32 {
+    match complexity {
+        LifeComplexity::Advanced => 6,
+        LifeComplexity::Complex => 9,
+        LifeComplexity::Simple => 12,
+        _ => 13,
+    }
+}
+
+/// Tenths still available to a newcomer, counting what the natives could give up while
+/// remaining at least a tenth of their own world.
+fn share_left(populations: &[SophontPopulation]) -> i32 {
+    let taken: i32 = populations.iter().map(|p| p.tenths).sum();
+    let yielding: i32 = populations
+        .iter()
+        .filter(|p| p.homeworld)
+        .map(|p| p.tenths - 1)
+        .sum();
+    10 - taken + yielding.max(0)
+}
+
+/// Adds a minority, crowding out the natives when the world is already full.
+fn add_minority(populations: &mut Vec<SophontPopulation>, mut minority: SophontPopulation) {
+    let left = share_left(populations);
+    if left <= 0 {
+        return;
+    }
+    minority.tenths = minority.tenths.min(left);
+    let taken: i32 = populations.iter().map(|p| p.tenths).sum();
+    let excess = taken + minority.tenths - 10;
+    if excess > 0 {
+        if let Some(natives) = populations.iter_mut().find(|p| p.homeworld) {
+            natives.tenths -= excess;
+        }
+    }
+    populations.push(minority);
+}
+
+/// Evolves native sophonts on worlds with rich enough life, then spreads
+/// them as minorities to populated worlds within their reach.
+pub fn generate_sophonts(seed: &Seed, systems: &mut [System]) -> Vec<Sophont> {
+    let mut rng = seed.subseed(vec!["sophonts"]).to_rng();
+    let mut sophonts = vec![];
+    let mut taken: HashSet<String> = HashSet::new();
+
+    for sys in systems.iter_mut() {
+        if sys.mainworld.population == 0 {
+            continue;
+        }
+        let complexity = sys.biosphere().complexity;
+        if rng.roll(2, 6, 0) < homeworld_target(complexity) {
+            continue;
+        }
+
+        let name = sophont_name(&mut rng);
+        let code = sophont_code(&name, &taken);
+        taken.insert(code.clone());
+        // Natives usually dominate their own world, unless settlers arrived in numbers
+        let tenths = match rng.roll(2, 6, 0) {
+            8.. => 10,
+            roll => roll.max(1),
+        };
+        sys.sophonts.push(SophontPopulation {
+            code: code.clone(),
+            name: name.clone(),
+            tenths,
+            homeworld: true,
+        });
+        sophonts.push(Sophont {
+            code,
+            name,
+            homeworld: sys.location,
+            traits: traits(&mut rng, &sys.mainworld),
+        });
+    }
+
+    for sophont in &sophonts {
+        let Some(home) = systems.iter().find(|sys| sys.location == sophont.homeworld) else {
+            continue;
+        };
+        let range = (1 + jump_for_tech(home.mainworld.tech)).min(4);
+        for sys in systems.iter_mut() {
+            let distance = sys.location.distance(&sophont.homeworld);
+            if distance == 0 || distance > range || sys.mainworld.population == 0 {
+                continue;
+            }
+            if rng.roll(2, 6, -distance) < 8 {
+                continue;
+            }
+            let tenths = rng.roll(1, 6, -3).clamp(0, 3);
+            add_minority(
+                &mut sys.sophonts,
+                SophontPopulation {
+                    code: sophont.code.clone(),
+                    name: sophont.name.clone(),
+                    tenths,
+                    homeworld: false,
+                },
+            );
+        }
+    }
+
+    for sys in systems.iter_mut() {
+        sys.sophonts
+            .sort_by(|a, b| b.homeworld.cmp(&a.homeworld).then(a.code.cmp(&b.code)));
+    }
+
+    sophonts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sophont_codes_are_unique() {
+        let mut taken = HashSet::new();
+        let first = sophont_code("Chirani", &taken);
+        taken.insert(first.clone());
+        let second = sophont_code("Chirani", &taken);
+        assert_eq!(first, "Chir");
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_minorities_crowd_out_natives() {
+        let population = |code: &str, tenths, homeworld| SophontPopulation {
+            code: String::from(code),
+            name: String::from(code),
+            tenths,
+            homeworld,
+        };
+        let mut populations = vec![population("Nnot", 10, true)];
+        add_minority(&mut populations, population("Xair", 2, false));
+        assert_eq!(populations[0].tenths, 8);
+        assert_eq!(populations[1].tenths, 2);
+
+        let mut populations = vec![population("Nnot", 1, true), population("Xair", 9, false)];
+        add_minority(&mut populations, population("Chir", 3, false));
+        assert_eq!(populations.len(), 2);
+    }
+
+    #[test]
+    fn test_shares_never_exceed_the_world() {
+        let seed = Seed::new(String::from("test"));
+        let sector = generate_sector(seed, Coordinate::new(0, 0), 32, 40, &Density::Dense);
+        for sys in &sector.systems {
+            let tenths: i32 = sys.sophonts.iter().map(|p| p.tenths).sum();
+            assert!(tenths <= 10, "{} has {tenths} tenths", sys.location);
+        }
+    }
+}
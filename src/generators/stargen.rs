@@ -128,8 +128,8 @@ fn generate_companion<R: Rollable>(
     }
 }
 
-pub fn generate_stars<R: Rollable>(rng: &mut R) -> Vec<Star> {
-    let primary_spectral_flux = rng.flux(0);
+pub fn generate_stars<R: Rollable>(rng: &mut R, spectral_dm: i32) -> Vec<Star> {
+    let primary_spectral_flux = rng.flux(spectral_dm).clamp(-6, 6);
     let primary_size_flux = rng.flux(0);
     let primary = generate_star(
         primary_spectral_flux,
@@ -149,14 +149,14 @@ pub fn generate_stars<R: Rollable>(rng: &mut R) -> Vec<Star> {
                 if s1 == s2 {
                     l2.cmp(l1).reverse()
                 } else {
-                    s1.cmp(&s2)
+                    s1.cmp(s2)
                 }
             } else {
-                a.cmp(&b)
+                a.cmp(b)
             }
         }
-        (_, _) => a.cmp(&b),
+        (_, _) => a.cmp(b),
     });
 
-    return stars;
+    stars
 }
@@ -1,6 +1,18 @@
 use crate::prelude::*;
 
-const STARPORT_DISTRIBUTION: [&str; 11] = ["A", "A", "A", "B", "B", "C", "C", "D", "E", "E", "X"];
+const STARPORT_DISTRIBUTION: [Starport; 11] = [
+    Starport::A,
+    Starport::A,
+    Starport::A,
+    Starport::B,
+    Starport::B,
+    Starport::C,
+    Starport::C,
+    Starport::D,
+    Starport::E,
+    Starport::E,
+    Starport::X,
+];
 
 pub fn generate_mainworld<R: Rollable>(
     rng: &mut R,
@@ -14,17 +26,17 @@ pub fn generate_mainworld<R: Rollable>(
     let port = roll_starport(rng.roll(2, 6, -2));
 
     let naval_roll = rng.roll(2, 6, 0);
-    let naval_base = match port.as_str() {
-        "A" => naval_roll <= 6,
-        "B" => naval_roll <= 5,
+    let naval_base = match port {
+        Starport::A => naval_roll <= 6,
+        Starport::B => naval_roll <= 5,
         _ => false,
     };
     let scout_roll = rng.roll(2, 6, 0);
-    let scout_base = match port.as_str() {
-        "A" => scout_roll <= 4,
-        "B" => scout_roll <= 5,
-        "C" => scout_roll <= 6,
-        "D" => scout_roll <= 7,
+    let scout_base = match port {
+        Starport::A => scout_roll <= 4,
+        Starport::B => scout_roll <= 5,
+        Starport::C => scout_roll <= 6,
+        Starport::D => scout_roll <= 7,
         _ => false,
     };
 
@@ -81,7 +93,7 @@ pub fn generate_mainworld<R: Rollable>(
             1,
             6,
             tech_mod(
-                &port,
+                port,
                 size,
                 atmosphere,
                 hydrographics,
@@ -91,8 +103,8 @@ pub fn generate_mainworld<R: Rollable>(
         )
         .clamp(0, 33);
 
-    let travel_zone = match (port.as_str(), government + law) {
-        ("X", _) | (_, 22..=32) => TravelZone::Red,
+    let travel_zone = match (port, government + law) {
+        (Starport::X, _) | (_, 22..=32) => TravelZone::Red,
         (_, 20 | 21) => TravelZone::Amber,
         _ => TravelZone::Green,
     };
@@ -115,8 +127,79 @@ pub fn generate_mainworld<R: Rollable>(
     }
 }
 
-fn roll_starport(roll: i32) -> String {
-    String::from(STARPORT_DISTRIBUTION[roll as usize])
+/// Rolls one of the system's other worlds. They may be any size, but are less
+/// populous and less developed than an inhabited mainworld.
+pub fn generate_secondary_world<R: Rollable>(
+    rng: &mut R,
+    mainworld: &World,
+    habitable_zone: i32,
+) -> World {
+    let mainworld_type = mainworld_type(rng.flux(0), rng.flux(0));
+    let orbit = rng.roll(2, 6, -2);
+    let hz_variance = (orbit - habitable_zone).clamp(-2, 2);
+
+    let size = match rng.roll(2, 6, -2) {
+        10 => rng.roll(1, 6, 9),
+        roll => roll,
+    };
+    let atmosphere = match size {
+        0 => 0,
+        _ => rng.flux(size).clamp(0, 15),
+    };
+    let hydrographics = match (size, atmosphere) {
+        (0 | 1, _) => 0,
+        (_, 0..=2 | 10..=15) => rng.flux(atmosphere - 4).clamp(0, 10),
+        (_, _) => rng.flux(atmosphere).clamp(0, 10),
+    };
+
+    let population = rng.roll(2, 6, -2).min(mainworld.population - 1).max(0);
+    let population_digit = match population {
+        0 => 0,
+        _ => rng.roll(1, 9, 0),
+    };
+    let government = match population {
+        0 => 0,
+        _ => rng.flux(population).clamp(0, 15),
+    };
+    let law = match population {
+        0 => 0,
+        _ => rng.flux(government).clamp(0, 18),
+    };
+    let tech = match population {
+        0 => 0,
+        _ => (mainworld.tech - 1).max(0),
+    };
+    let port = roll_spaceport(population + rng.flux(0));
+
+    World {
+        mainworld_type,
+        hz_variance,
+        orbit,
+        port,
+        bases: Vec::new(),
+        size,
+        atmosphere,
+        hydrographics,
+        population,
+        population_digit,
+        government,
+        law,
+        tech,
+        travel_zone: TravelZone::Green,
+    }
+}
+
+fn roll_spaceport(roll: i32) -> Starport {
+    match roll {
+        ..=2 => Starport::Y,
+        3 | 4 => Starport::H,
+        5 | 6 => Starport::G,
+        _ => Starport::F,
+    }
+}
+
+fn roll_starport(roll: i32) -> Starport {
+    STARPORT_DISTRIBUTION[roll as usize]
 }
 
 fn mainworld_type(flux: i32, orbit_roll: i32) -> MainWorldType {
@@ -130,7 +213,7 @@ fn mainworld_type(flux: i32, orbit_roll: i32) -> MainWorldType {
 }
 
 fn tech_mod(
-    port: &str,
+    port: Starport,
     size: i32,
     atmosphere: i32,
     hydrographics: i32,
@@ -145,13 +228,12 @@ fn tech_mod(
         + government_tech(government)
 }
 
-fn port_tech(port: &str) -> i32 {
+fn port_tech(port: Starport) -> i32 {
     match port {
-        "A" => 6,
-        "B" => 4,
-        "C" => 2,
-        "F" => 1,
-        "X" => -4,
+        Starport::A => 6,
+        Starport::B => 4,
+        Starport::C => 2,
+        Starport::X => -4,
         _ => 0,
     }
 }
@@ -191,14 +273,26 @@ fn population_tech(population: i32) -> i32 {
 fn government_tech(government: i32) -> i32 {
     match government {
         0 | 5 => 1,
-    13 => -2,
+        13 => -2,
         _ => 0,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::population_tech;
+    use super::*;
+
+    #[test]
+    fn test_secondary_worlds_are_less_developed() {
+        let mut rng = Seed::new(String::from("test")).to_rng();
+        let mainworld = generate_mainworld(&mut rng, 0, 3);
+        for _ in 0..100 {
+            let world = generate_secondary_world(&mut rng, &mainworld, 3);
+            assert!(world.population < mainworld.population.max(1));
+            assert!(world.tech < mainworld.tech.max(1));
+            assert!(world.port.is_spaceport());
+        }
+    }
 
     #[test]
     fn test_tech_low_pop_mod() {
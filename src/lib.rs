@@ -1,5 +1,6 @@
 pub mod entities;
 pub mod generators;
+pub mod render;
 
 pub mod prelude {
     pub use crate::entities::*;
@@ -1,5 +1,22 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use witchspace::prelude::*;
+use witchspace::render::{sector_map, Description, Gazetteer};
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Format {
+    /// Traveller Map style tab separated listing
+    Text,
+    /// Hex map with borders
+    Svg,
+    /// Traveller Map sector metadata with allegiances and borders
+    Xml,
+    /// Population and gross product of the sector, its subsectors and polities
+    Economy,
+    /// Library data booklet, or with --hex a description of one system
+    Markdown,
+    /// Library data booklet as an HTML page, or with --hex a description of one system
+    Html,
+}
 
 /// Generate a traveller main world profile
 #[derive(Parser)]
@@ -7,6 +24,62 @@ struct Cli {
     /// A seed for the PRNG
     #[arg(long)]
     seed: Option<String>,
+
+    /// Stellar density: rift, sparse, standard, dense or cluster
+    #[arg(long, default_value_t = Density::Standard)]
+    density: Density,
+
+    /// Derive density from the galaxy's core, spiral arms and voids instead
+    #[arg(long)]
+    structure: bool,
+
+    /// Sector to generate, as row and column offsets from the core
+    #[arg(long, num_args = 2, value_names = ["ROW", "COL"], allow_negative_numbers = true, default_values_t = [0, 0])]
+    sector: Vec<i32>,
+
+    /// System to describe with --format markdown or html, or to show traffic for with --date, as XXYY
+    #[arg(long)]
+    hex: Option<Coordinate>,
+
+    /// Plan a route between two hexes, as XXYY, refuelling at systems and depots
+    #[arg(long, num_args = 2, value_names = ["FROM", "TO"])]
+    route: Option<Vec<Coordinate>>,
+
+    /// Jump rating of the ship following --route
+    #[arg(long, default_value_t = 2)]
+    jump: i32,
+
+    /// Include the sector map in the library data booklet
+    #[arg(long)]
+    map: bool,
+
+    /// Nobility rules for every polity: none, imperial or capitals
+    #[arg(long)]
+    nobility: Option<NobilityRules>,
+
+    /// Show the ships arriving at --hex on this date, written in the campaign calendar
+    #[arg(long, requires = "hex")]
+    date: Option<String>,
+
+    /// Imperial year that is year 0 of the campaign calendar
+    #[arg(long, allow_negative_numbers = true, default_value_t = 0)]
+    epoch: i32,
+
+    /// Era written after campaign years, e.g. AD
+    #[arg(long, default_value = "")]
+    era: String,
+
+    /// How campaign dates are written: day-year, year-day or long
+    #[arg(long, default_value_t = DateFormat::DayYear)]
+    date_format: DateFormat,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Write the output to this file, such as booklet.html, instead of stdout
+    #[arg(long)]
+    output: Option<std::path::PathBuf>,
 }
 
 fn main() {
@@ -15,6 +88,94 @@ fn main() {
         Some(seed) => Galaxy::new(seed),
         None => Galaxy::random(),
     };
-    let sector = galaxy.get_sector(Coordinate::new(0, 0));
-    println!("{sector}")
+    let galaxy = if args.structure {
+        galaxy.with_structure()
+    } else {
+        galaxy.with_density(args.density)
+    };
+    let galaxy = match args.nobility {
+        Some(rules) => galaxy.with_nobility(rules),
+        None => galaxy,
+    };
+    let galaxy = galaxy.with_calendar(Calendar::new(args.epoch, &args.era, args.date_format));
+    let sector = galaxy.get_sector(Coordinate::new(args.sector[0], args.sector[1]));
+    let find = |hex: Coordinate| {
+        sector
+            .systems
+            .iter()
+            .find(|sys| sys.location == hex)
+            .unwrap_or_else(|| {
+                eprintln!("No system at hex {hex}");
+                std::process::exit(1)
+            })
+    };
+    let describe = |hex: Coordinate| {
+        let sys = find(hex);
+        Description::new(sys, sector.polity(&sys.allegiance))
+    };
+    let gazetteer = match args.map {
+        true => Gazetteer::new(&sector).with_map(),
+        false => Gazetteer::new(&sector),
+    };
+    let output = if let Some(route) = args.route {
+        match sector.jump_route(route[0], route[1], args.jump) {
+            Some(hexes) => hexes
+                .iter()
+                .map(|hex| hex.to_string())
+                .collect::<Vec<String>>()
+                .join(" "),
+            None => {
+                eprintln!(
+                    "No jump-{} route from {} to {}",
+                    args.jump, route[0], route[1]
+                );
+                std::process::exit(1)
+            }
+        }
+    } else if let (Some(hex), Some(date)) = (args.hex, &args.date) {
+        let calendar = galaxy.calendar();
+        let date = calendar.parse(date).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(1)
+        });
+        let sys = find(hex);
+        let traffic = Traffic::new(sys, date);
+        std::iter::once(format!(
+            "{} {} on {}: {} arrivals",
+            sys.location,
+            sys.name,
+            calendar.format(date),
+            traffic.arrivals.len()
+        ))
+        .chain(
+            traffic
+                .arrivals
+                .iter()
+                .map(|encounter| encounter.to_string()),
+        )
+        .collect::<Vec<String>>()
+        .join("\n")
+    } else {
+        match (args.format, args.hex) {
+            (Format::Text | Format::Svg | Format::Xml | Format::Economy, Some(_)) => {
+                eprintln!("--hex needs --date, or --format markdown or html");
+                std::process::exit(2)
+            }
+            (Format::Text, None) => sector.to_string(),
+            (Format::Svg, None) => sector_map(&sector),
+            (Format::Xml, None) => sector.metadata_xml(),
+            (Format::Economy, None) => sector.economy_report(),
+            (Format::Markdown, Some(hex)) => describe(hex).to_markdown(),
+            (Format::Markdown, None) => gazetteer.to_markdown(),
+            (Format::Html, Some(hex)) => describe(hex).to_html(),
+            (Format::Html, None) => gazetteer.to_html(),
+        }
+    };
+    match args.output {
+        Some(path) => std::fs::write(&path, output + "\n").unwrap_or_else(|e| {
+            eprintln!("Could not write {}: {e}", path.display());
+            std::process::exit(1)
+        }),
+        None => println!("{output}"),
+    }
 }
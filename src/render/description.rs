@@ -0,0 +1,503 @@
+use crate::prelude::*;
+
+pub(crate) fn atmosphere_name(atmosphere: i32) -> &'static str {
+    match atmosphere {
+        0 => "no atmosphere",
+        1 => "a trace atmosphere",
+        2 => "a very thin, tainted atmosphere",
+        3 => "a very thin atmosphere",
+        4 => "a thin, tainted atmosphere",
+        5 => "a thin atmosphere",
+        6 => "a standard atmosphere",
+        7 => "a standard, tainted atmosphere",
+        8 => "a dense atmosphere",
+        9 => "a dense, tainted atmosphere",
+        10 => "an exotic atmosphere",
+        11 => "a corrosive atmosphere",
+        12 => "an insidious atmosphere",
+        13 => "a very dense atmosphere",
+        14 => "a low atmosphere",
+        _ => "an unusual atmosphere",
+    }
+}
+
+/// A world's size, as "asteroid belt" or its diameter.
+pub(crate) fn size_name(size: i32) -> String {
+    match size {
+        0 => String::from("asteroid belt"),
+        size => format!("{} km", size * 1600),
+    }
+}
+
+/// The most serious weapons ban under a law profile.
+pub(crate) fn weapons_law(law: &LawProfile) -> String {
+    match law.restrictions(LawCategory::Weapons).last() {
+        Some(banned) => format!("weapons law extends to {}", banned.to_lowercase()),
+        None => String::from("there are no restrictions on weapons"),
+    }
+}
+
+fn atmosphere_note(atmosphere: i32) -> &'static str {
+    match atmosphere {
+        0 | 1 => "Vacc suits are required outdoors.",
+        2..=5 => "Visitors will need a compressor or filter mask to breathe.",
+        6 | 8 => "The air is breathable without assistance.",
+        7 | 9 => "A filter mask is needed to breathe safely.",
+        10 => "An air supply is required outdoors.",
+        _ => "Full protective suits are required outdoors.",
+    }
+}
+
+fn hydrographics_text(world: &World) -> String {
+    match world.hydrographics {
+        0 => String::from("There is no free standing water."),
+        10.. => String::from("Almost the entire surface is covered by water."),
+        hydro if world.is_fluid_oceans() => {
+            format!(
+                "About {}% of the surface is covered by oceans of fluids other than water.",
+                hydro * 10
+            )
+        }
+        hydro => format!("About {}% of the surface is covered by water.", hydro * 10),
+    }
+}
+
+pub(crate) fn tech_era(tech: i32) -> &'static str {
+    match tech {
+        0 => "stone age",
+        1..=3 => "pre-industrial",
+        4..=6 => "industrial",
+        7..=9 => "pre-stellar",
+        10..=11 => "early stellar",
+        12..=14 => "average stellar",
+        _ => "high stellar",
+    }
+}
+
+fn population_text(population: i64) -> String {
+    match population {
+        0 => String::from("no permanent inhabitants"),
+        1..=999 => format!("a handful of inhabitants ({population})"),
+        1_000..=999_999 => format!("about {} thousand inhabitants", population / 1_000),
+        1_000_000..=999_999_999 => format!("about {} million inhabitants", population / 1_000_000),
+        _ => format!("about {} billion inhabitants", population / 1_000_000_000),
+    }
+}
+
+fn facilities_text(port: Starport) -> String {
+    let facilities = port.facilities();
+    let fuel = match facilities.fuel {
+        FuelQuality::Refined => "refined fuel",
+        FuelQuality::Unrefined => "unrefined fuel",
+        FuelQuality::None => "no fuel",
+    };
+    let shipyard = match facilities.shipyard {
+        Shipyard::Starships => ", a shipyard able to build starships",
+        Shipyard::Spacecraft => ", a shipyard able to build non-starships",
+        Shipyard::None => "",
+    };
+    let repair = match facilities.repair {
+        Repair::Overhaul => ", and can overhaul any ship",
+        Repair::Major => ", and can make major repairs",
+        Repair::Minor => ", and can make minor repairs",
+        Repair::None => ", and no repair facilities",
+    };
+    let highport = match facilities.highport {
+        true => " An orbital highport serves ships that do not land.",
+        false => "",
+    };
+    format!("It offers {fuel}{shipyard}{repair}.{highport}")
+}
+
+fn biosphere_text(biosphere: &Biosphere) -> String {
+    if !biosphere.has_life() {
+        return String::from("No native life has been found.");
+    }
+    let abundance = match biosphere.biomass {
+        1..=3 => "scarce",
+        4..=7 => "common",
+        _ => "abundant",
+    };
+    let compatibility = match biosphere.compatibility {
+        0..=2 => "toxic to humans",
+        3..=6 => "partly compatible with human biochemistry",
+        _ => "largely edible by humans",
+    };
+    format!(
+        "Native life is {} and {abundance}, and {compatibility}.",
+        biosphere.complexity.to_string().to_lowercase()
+    )
+}
+
+fn military_text(military: &Military) -> String {
+    if military.is_undefended() {
+        return String::from("The world is undefended.");
+    }
+    let mut forces = vec![format!("{} battalions of troops", military.garrison)];
+    if military.system_squadrons > 0 {
+        forces.push(format!(
+            "{} squadrons of system defence boats",
+            military.system_squadrons
+        ));
+    }
+    if military.naval_squadrons > 0 {
+        forces.push(format!(
+            "{} naval squadrons capable of jump-{}",
+            military.naval_squadrons, military.jump
+        ));
+    }
+    format!(
+        "It is defended by {}, behind planetary defences rated {} of 15.",
+        and_list(&forces),
+        military.planetary_defence
+    )
+}
+
+/// Puts "a" or "an" before a phrase. Codes such as spectral classes are read
+/// letter by letter, so "M2 V" takes "an" like "embassy" does.
+fn with_article(phrase: &str) -> String {
+    let word = phrase.split_whitespace().next().unwrap_or_default();
+    let is_code = word.len() <= 3
+        && word
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    let vowel_sound = match word.chars().next() {
+        Some(c) if is_code => "AEFHILMNORSX".contains(c),
+        Some(c) => "aeiouAEIOU".contains(c),
+        None => false,
+    };
+    match vowel_sound {
+        true => format!("an {phrase}"),
+        false => format!("a {phrase}"),
+    }
+}
+
+/// Joins a list into English, as "a, b and c".
+fn and_list(items: &[String]) -> String {
+    match items.split_last() {
+        None => String::new(),
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+pub(crate) fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A player handout describing a system's mainworld in plain English.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+    pub title: String,
+    pub paragraphs: Vec<String>,
+}
+
+impl Description {
+    /// Describes a system, naming its polity when one is given.
+    pub fn new(sys: &System, polity: Option<&Polity>) -> Self {
+        let world = &sys.mainworld;
+        let title = format!("{} ({}) {}", sys.name, sys.location, world);
+        let mut paragraphs = vec![];
+
+        // Physical
+        let size = match world.size {
+            0 => format!("It is an {} rather than a single world", size_name(0)),
+            size => format!(
+                "It is about {} across, with a surface gravity of around {:.1} G",
+                size_name(size),
+                size as f64 / 8.0
+            ),
+        };
+        // Codes read straight off the profile would only repeat the sentences above
+        let physical = [TradeCodeCategory::Planetary, TradeCodeCategory::Climate]
+            .iter()
+            .flat_map(|category| {
+                sys.trade_codes()
+                    .in_category(*category)
+                    .collect::<Vec<TradeCode>>()
+            })
+            .filter(|code| {
+                !matches!(
+                    code,
+                    TradeCode::AsteroidBelt
+                        | TradeCode::Vacuum
+                        | TradeCode::Desert
+                        | TradeCode::FluidOceans
+                        | TradeCode::OceanWorld
+                        | TradeCode::WaterWorld
+                )
+            })
+            .map(|code| code.description())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let sentences = [
+            format!(
+                "{} lies in hex {} of subsector {}, orbiting {} star.",
+                sys.name,
+                sys.location,
+                sys.location.subsector(),
+                with_article(&sys.stars[0].to_string()),
+            ),
+            format!("{size}, and has {}.", atmosphere_name(world.atmosphere)),
+            atmosphere_note(world.atmosphere).to_string(),
+            hydrographics_text(world),
+            physical,
+            biosphere_text(&sys.biosphere()),
+        ];
+        paragraphs.push(
+            sentences
+                .into_iter()
+                .filter(|sentence| !sentence.is_empty())
+                .collect::<Vec<String>>()
+                .join(" "),
+        );
+
+        // Wildlife
+        let biosphere = sys.biosphere();
+        paragraphs.extend(biosphere.terrain.iter().filter_map(|terrain| {
+            let table = biosphere.encounter_table(*terrain);
+            match table.is_empty() {
+                true => None,
+                false => Some(format!(
+                    "{terrain} encounters (1D): {}.",
+                    table
+                        .iter()
+                        .map(|encounter| encounter.to_string())
+                        .collect::<Vec<String>>()
+                        .join("; ")
+                )),
+            }
+        }));
+
+        // People
+        let allegiance = match polity {
+            Some(polity) => format!("It is a member of the {} {}.", polity.name, polity.kind),
+            None if sys.is_aligned() => format!("It owes allegiance to {}.", sys.allegiance),
+            None => String::from("It is independent of any interstellar polity."),
+        };
+        if world.population == 0 {
+            paragraphs.push(format!(
+                "The world has no permanent inhabitants. {allegiance}"
+            ));
+        } else {
+            let government = sys.government();
+            let law = world.law_profile();
+            let government = match government.kind {
+                GovernmentType::None => {
+                    String::from("It has no formal government, and family bonds predominate.")
+                }
+                kind => format!(
+                    "Its government is {}: {}.",
+                    with_article(&kind.to_string().to_lowercase()),
+                    kind.description().to_lowercase()
+                ),
+            };
+            paragraphs.push(format!(
+                "The world has {}. {government} {allegiance} Its law level is {}, and {}. {}% of arrivals are stopped by customs.",
+                population_text(sys.total_population()),
+                world.law,
+                weapons_law(&law),
+                (law.customs_odds() * 100.0).round(),
+            ));
+        }
+
+        // Starport, technology and bases
+        let port = match world.port {
+            Starport::X => String::from("The world has no starport."),
+            port => format!(
+                "The world has a class {port} starport of {} quality. {}",
+                port.quality().to_lowercase(),
+                facilities_text(port)
+            ),
+        };
+        let bases = world
+            .bases
+            .iter()
+            .map(|base| base.name().to_lowercase())
+            .map(|name| with_article(&name))
+            .collect::<Vec<String>>();
+        let bases = match bases.is_empty() {
+            true => String::new(),
+            false => format!(" The system hosts {}.", and_list(&bases)),
+        };
+        paragraphs.push(format!(
+            "{port} Its technology is of the {} era (tech level {}).{bases} {}",
+            tech_era(world.tech),
+            world.tech,
+            military_text(&sys.military())
+        ));
+
+        // Trade and economy
+        if world.population > 0 {
+            let codes = sys
+                .trade_codes()
+                .iter()
+                .filter(|code| {
+                    !matches!(
+                        code.category(),
+                        TradeCodeCategory::Planetary | TradeCodeCategory::Climate
+                    )
+                })
+                .map(|code| code.name().to_string())
+                .collect::<Vec<String>>();
+            let codes = match codes.is_empty() {
+                true => String::new(),
+                false => format!("Its trade classifications are {}. ", and_list(&codes)),
+            };
+            let importance = match sys.extensions.importance {
+                ..=-1 => "an unimportant backwater",
+                0..=1 => "of ordinary importance",
+                2..=3 => "an important world",
+                _ => "a world of great importance",
+            };
+            paragraphs.push(format!(
+                "{codes}It is {importance}, with a gross world product of {} MCr and an average income of {} Cr per person.",
+                sys.gross_world_product(),
+                sys.per_capita_income()
+            ));
+            paragraphs.extend(sys.culture().map(|culture| culture.narrative()));
+        }
+
+        if let Some(site) = &sys.ancient_site {
+            paragraphs.push(format!(
+                "Explorers have found remains here. {}",
+                site.description()
+            ));
+        }
+
+        // Travel advisory
+        match world.travel_zone {
+            TravelZone::Green => (),
+            TravelZone::Amber => paragraphs.push(String::from(
+                "The Travellers' Aid Society has declared the world an Amber Zone. Travellers should exercise caution.",
+            )),
+            TravelZone::Red => paragraphs.push(String::from(
+                "The world is a Red Zone. Travel here is forbidden and the interdiction is enforced.",
+            )),
+        }
+
+        Self { title, paragraphs }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        format!("## {}\n\n{}\n", self.title, self.paragraphs.join("\n\n"))
+    }
+
+    pub fn to_html(&self) -> String {
+        let paragraphs = self
+            .paragraphs
+            .iter()
+            .map(|p| format!("<p>{}</p>", escape_html(p)))
+            .collect::<Vec<String>>()
+            .join("\n");
+        format!(
+            "<article>\n<h2>{}</h2>\n{paragraphs}\n</article>",
+            escape_html(&self.title)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description() {
+        let seed = Seed::new(String::from("test"));
+        let sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        let description = Description::new(&sys, None);
+        assert!(description.paragraphs.len() >= 3);
+        assert!(description.to_markdown().starts_with("## Anonymous (0101)"));
+        assert!(description.to_html().contains("<p>"));
+        assert!(description.paragraphs.iter().all(|p| !p.contains("  ")));
+    }
+
+    #[test]
+    fn test_vacuum_belt_is_described_once() {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.mainworld.size = 0;
+        sys.mainworld.atmosphere = 0;
+        sys.mainworld.hydrographics = 0;
+        let physical = &Description::new(&sys, None).paragraphs[0];
+        assert_eq!(physical.matches("asteroid belt").count(), 1);
+        assert_eq!(physical.matches("vacc suits").count(), 0);
+        assert_eq!(physical.matches("Vacc suits").count(), 1);
+        assert!(!physical.contains(TradeCode::AsteroidBelt.description()));
+    }
+
+    #[test]
+    fn test_lifeless_twilight_zone() {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.mainworld.orbit = 0;
+        sys.mainworld.atmosphere = 0;
+        assert!(sys.trade_codes().contains(TradeCode::TwilightZone));
+        assert!(!sys.biosphere().has_life());
+        let physical = &Description::new(&sys, None).paragraphs[0];
+        assert!(physical.contains(TradeCode::TwilightZone.description()));
+        assert!(!physical.to_lowercase().contains("life clings"));
+        assert_eq!(physical.matches("life").count(), 1);
+    }
+
+    #[test]
+    fn test_military_is_described() {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.mainworld.population = 7;
+        sys.mainworld.tech = 12;
+        sys.mainworld.bases = vec![Base::Naval];
+        let military = sys.military();
+        let description = Description::new(&sys, None).to_markdown();
+        assert!(description.contains(&format!("{} naval squadrons", military.naval_squadrons)));
+        sys.mainworld.population = 0;
+        assert!(Description::new(&sys, None)
+            .to_markdown()
+            .contains("The world is undefended."));
+    }
+
+    #[test]
+    fn test_encounter_tables_are_described() {
+        let seed = Seed::new(String::from("test"));
+        let mut sys = generate_system(seed, Coordinate::new(1, 1), StellarPopulation::Disc);
+        sys.mainworld.atmosphere = 0;
+        let description = Description::new(&sys, None).to_markdown();
+        assert!(!description.contains("encounters (1D)"));
+
+        sys.mainworld.size = 7;
+        sys.mainworld.atmosphere = 6;
+        sys.mainworld.hydrographics = 6;
+        let biosphere = sys.biosphere();
+        let description = Description::new(&sys, None).to_markdown();
+        let tables = biosphere
+            .terrain
+            .iter()
+            .map(|terrain| (terrain, biosphere.encounter_table(*terrain)))
+            .filter(|(_, table)| !table.is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(description.matches("encounters (1D)").count(), tables.len());
+        for (terrain, table) in tables {
+            assert!(description.contains(&format!("{terrain} encounters (1D): {};", table[0])));
+        }
+    }
+
+    #[test]
+    fn test_articles() {
+        assert_eq!(with_article("M2 V"), "an M2 V");
+        assert_eq!(with_article("G0 V"), "a G0 V");
+        assert_eq!(with_article("embassy"), "an embassy");
+        assert_eq!(with_article("naval base"), "a naval base");
+        assert_eq!(
+            with_article("impersonal bureaucracy"),
+            "an impersonal bureaucracy"
+        );
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(escape_html("<a & b>"), "&lt;a &amp; b&gt;");
+    }
+}
@@ -0,0 +1,406 @@
+use super::description::{atmosphere_name, escape_html, size_name, tech_era, weapons_law};
+use crate::prelude::*;
+use crate::render::{sector_map, Description};
+
+/// A "Library Data" booklet for a sector: title page, subsector index and an
+/// entry per system.
+#[derive(Debug, Clone)]
+pub struct Gazetteer<'a> {
+    sector: &'a Sector,
+    map: bool,
+}
+
+impl<'a> Gazetteer<'a> {
+    pub fn new(sector: &'a Sector) -> Self {
+        Self { sector, map: false }
+    }
+
+    /// Includes the sector map as an inline SVG image.
+    pub fn with_map(mut self) -> Self {
+        self.map = true;
+        self
+    }
+
+    fn title(&self) -> String {
+        format!(
+            "Library Data: Sector {},{}",
+            self.sector.location.row, self.sector.location.col
+        )
+    }
+
+    fn summary(&self) -> Vec<String> {
+        let economy = self.sector.economy();
+        vec![
+            format!("Seed: {}", self.sector.seed.top_level_seed),
+            format!("Systems: {}", self.sector.systems.len()),
+            format!("Population: {}", economy.population),
+            format!("Gross product: {} MCr", economy.gwp),
+        ]
+    }
+
+    fn polities(&self) -> Vec<String> {
+        self.sector
+            .polities
+            .iter()
+            .map(|p| format!("{}: {} {}", p.code, p.name, p.kind))
+            .collect()
+    }
+
+    fn sophonts(&self) -> Vec<String> {
+        self.sector
+            .sophonts
+            .iter()
+            .map(|s| {
+                format!(
+                    "{}: {}, homeworld {}, {}",
+                    s.code, s.name, s.homeworld, s.traits
+                )
+            })
+            .collect()
+    }
+
+    fn deep_space(&self) -> Vec<String> {
+        self.sector
+            .deep_space
+            .iter()
+            .map(|hex| hex.to_string())
+            .collect()
+    }
+
+    fn subsector(&self, subsector: char) -> Vec<&'a System> {
+        self.sector
+            .systems
+            .iter()
+            .filter(|sys| sys.location.subsector() == subsector)
+            .collect()
+    }
+
+    /// The UWP and other profile data of a system, one labelled field at a time.
+    fn profile(&self, sys: &System) -> Vec<(&'static str, String)> {
+        let world = &sys.mainworld;
+        let uwp: Vec<char> = world.to_string().chars().collect();
+        let digit = |i: usize, text: String| format!("{} ({text})", uwp[i]);
+        let nobility = self.sector.nobility(sys);
+        let allegiance = match self.sector.polity(&sys.allegiance) {
+            Some(polity) => format!("{} {} {}", polity.code, polity.name, polity.kind),
+            None => String::from("Non-aligned"),
+        };
+        let bases = world
+            .bases
+            .iter()
+            .map(|base| base.name())
+            .collect::<Vec<&str>>()
+            .join(", ");
+        vec![
+            ("Starport", digit(0, world.port.quality().to_string())),
+            ("Size", digit(1, size_name(world.size))),
+            (
+                "Atmosphere",
+                digit(
+                    2,
+                    atmosphere_name(world.atmosphere)
+                        .trim_start_matches("an ")
+                        .trim_start_matches("a ")
+                        .to_string(),
+                ),
+            ),
+            (
+                "Hydrographics",
+                digit(3, format!("{}%", world.hydrographics.clamp(0, 10) * 10)),
+            ),
+            ("Population", digit(4, sys.total_population().to_string())),
+            (
+                "Government",
+                digit(5, GovernmentType::from_code(world.government).to_string()),
+            ),
+            (
+                "Law",
+                digit(
+                    6,
+                    format!(
+                        "{}; customs stop {:.0}% of arrivals",
+                        weapons_law(&world.law_profile()),
+                        world.law_profile().customs_odds() * 100.0
+                    ),
+                ),
+            ),
+            ("Tech", digit(8, tech_era(world.tech).to_string())),
+            ("Remarks", sys.remarks()),
+            ("Stars", sys.stars()),
+            ("Extensions", sys.extensions.to_string()),
+            (
+                "Nobility",
+                if nobility.is_empty() {
+                    String::from("None")
+                } else {
+                    nobility
+                },
+            ),
+            (
+                "Bases",
+                if bases.is_empty() {
+                    String::from("None")
+                } else {
+                    bases
+                },
+            ),
+            (
+                "Zone",
+                match world.travel_zone {
+                    TravelZone::Green => String::from("Green"),
+                    TravelZone::Amber => String::from("Amber"),
+                    TravelZone::Red => String::from("Red"),
+                },
+            ),
+            ("PBG", sys.pbg()),
+            ("Allegiance", allegiance),
+        ]
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = vec![format!("# {}", self.title())];
+        out.push(self.summary().join("  \n"));
+        let polities = self.polities();
+        if !polities.is_empty() {
+            out.push(String::from("## Allegiances"));
+            out.push(
+                polities
+                    .iter()
+                    .map(|p| format!("- {p}"))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            );
+        }
+        let sophonts = self.sophonts();
+        if !sophonts.is_empty() {
+            out.push(String::from("## Sophonts"));
+            out.push(
+                sophonts
+                    .iter()
+                    .map(|s| format!("- {s}"))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            );
+        }
+        if self.map {
+            out.push(String::from("## Map"));
+            out.push(sector_map(self.sector));
+        }
+
+        out.push(String::from("## Subsector Index"));
+        for subsector in 'A'..='P' {
+            let systems = self.subsector(subsector);
+            if systems.is_empty() {
+                continue;
+            }
+            out.push(format!("### Subsector {subsector}"));
+            let rows = systems
+                .iter()
+                .map(|sys| {
+                    format!(
+                        "| [{}](#hex-{}) | {} | {} | {} |",
+                        sys.location,
+                        sys.location,
+                        sys.name,
+                        sys.mainworld,
+                        sys.remarks()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            out.push(format!(
+                "| Hex | Name | UWP | Remarks |\n|-----|------|-----|---------|\n{rows}"
+            ));
+        }
+
+        let deep_space = self.deep_space();
+        if !deep_space.is_empty() {
+            out.push(String::from("## Deep Space"));
+            out.push(
+                deep_space
+                    .iter()
+                    .map(|hex| format!("- {hex}"))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            );
+        }
+
+        out.push(String::from("## Systems"));
+        for sys in &self.sector.systems {
+            let description = Description::new(sys, self.sector.polity(&sys.allegiance));
+            out.push(format!(
+                "<a id=\"hex-{}\"></a>\n\n### {}",
+                sys.location, description.title
+            ));
+            let profile = self
+                .profile(sys)
+                .iter()
+                .map(|(label, value)| format!("| {label} | {value} |"))
+                .collect::<Vec<String>>()
+                .join("\n");
+            out.push(format!("| | |\n|---|---|\n{profile}"));
+            out.extend(description.paragraphs);
+        }
+        out.join("\n\n") + "\n"
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut body = vec![
+            format!("<h1>{}</h1>", escape_html(&self.title())),
+            format!(
+                "<p>{}</p>",
+                self.summary()
+                    .iter()
+                    .map(|line| escape_html(line))
+                    .collect::<Vec<String>>()
+                    .join("<br>\n")
+            ),
+        ];
+        let polities = self.polities();
+        if !polities.is_empty() {
+            body.push(String::from("<h2>Allegiances</h2>"));
+            body.push(format!(
+                "<ul>\n{}\n</ul>",
+                polities
+                    .iter()
+                    .map(|p| format!("<li>{}</li>", escape_html(p)))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ));
+        }
+        let sophonts = self.sophonts();
+        if !sophonts.is_empty() {
+            body.push(String::from("<h2>Sophonts</h2>"));
+            body.push(format!(
+                "<ul>\n{}\n</ul>",
+                sophonts
+                    .iter()
+                    .map(|s| format!("<li>{}</li>", escape_html(s)))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ));
+        }
+        if self.map {
+            body.push(String::from("<h2>Map</h2>"));
+            body.push(sector_map(self.sector));
+        }
+
+        body.push(String::from("<h2>Subsector Index</h2>"));
+        for subsector in 'A'..='P' {
+            let systems = self.subsector(subsector);
+            if systems.is_empty() {
+                continue;
+            }
+            let rows = systems
+                .iter()
+                .map(|sys| {
+                    format!(
+                        "<tr><td><a href=\"#hex-{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                        sys.location,
+                        sys.location,
+                        escape_html(&sys.name),
+                        sys.mainworld,
+                        sys.remarks()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            body.push(format!(
+                "<h3>Subsector {subsector}</h3>\n<table>\n<tr><th>Hex</th><th>Name</th><th>UWP</th><th>Remarks</th></tr>\n{rows}\n</table>"
+            ));
+        }
+
+        let deep_space = self.deep_space();
+        if !deep_space.is_empty() {
+            body.push(String::from("<h2>Deep Space</h2>"));
+            body.push(format!(
+                "<ul>\n{}\n</ul>",
+                deep_space
+                    .iter()
+                    .map(|hex| format!("<li>{}</li>", escape_html(hex)))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ));
+        }
+
+        body.push(String::from("<h2>Systems</h2>"));
+        for sys in &self.sector.systems {
+            let description = Description::new(sys, self.sector.polity(&sys.allegiance));
+            let profile = self
+                .profile(sys)
+                .iter()
+                .map(|(label, value)| {
+                    format!("<tr><th>{label}</th><td>{}</td></tr>", escape_html(value))
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            let paragraphs = description
+                .paragraphs
+                .iter()
+                .map(|p| format!("<p>{}</p>", escape_html(p)))
+                .collect::<Vec<String>>()
+                .join("\n");
+            body.push(format!(
+                "<article id=\"hex-{}\">\n<h3>{}</h3>\n<table>\n{profile}\n</table>\n{paragraphs}\n</article>",
+                sys.location,
+                escape_html(&description.title)
+            ));
+        }
+
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\nbody {{ font-family: sans-serif; max-width: 60em; margin: auto; }}\ntable {{ border-collapse: collapse; }}\nth, td {{ text-align: left; padding: 0 1em 0 0; }}\narticle {{ break-inside: avoid; }}\n</style>\n</head>\n<body>\n{}\n</body>\n</html>",
+            escape_html(&self.title()),
+            body.join("\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() -> Sector {
+        let seed = Seed::new(String::from("test"));
+        generate_sector(seed, Coordinate::new(0, 0), 8, 10, &Density::Standard)
+    }
+
+    #[test]
+    fn test_every_system_has_an_entry() {
+        let sector = setup();
+        let markdown = Gazetteer::new(&sector).to_markdown();
+        let html = Gazetteer::new(&sector).to_html();
+        for sys in &sector.systems {
+            let anchor = format!("id=\"hex-{}\"", sys.location);
+            assert!(markdown.contains(&anchor));
+            assert!(html.contains(&anchor));
+        }
+    }
+
+    #[test]
+    fn test_profile_reads_like_the_description() {
+        let mut sector = setup();
+        sector.systems[0].mainworld.size = 0;
+        sector.systems[0].mainworld.law = 0;
+        let gazetteer = Gazetteer::new(&sector);
+        let profile = gazetteer.profile(&sector.systems[0]);
+        let row = |label: &str| {
+            profile
+                .iter()
+                .find(|(l, _)| *l == label)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+        assert_eq!(row("Size"), "0 (asteroid belt)");
+        assert!(row("Law").starts_with("0 (there are no restrictions on weapons;"));
+    }
+
+    #[test]
+    fn test_map_is_optional() {
+        let sector = setup();
+        assert!(!Gazetteer::new(&sector).to_html().contains("<svg"));
+        assert!(Gazetteer::new(&sector)
+            .with_map()
+            .to_html()
+            .contains("<svg"));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod svg;
+pub use svg::*;
+pub mod description;
+pub use description::*;
+pub mod gazetteer;
+pub use gazetteer::*;
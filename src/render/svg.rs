@@ -0,0 +1,130 @@
+use std::hash::Hasher;
+
+use super::description::escape_html;
+use crate::prelude::*;
+
+/// Pixels per step of the `Coordinate::vertices` lattice.
+const UNIT_X: f64 = 10.0;
+const UNIT_Y: f64 = 17.32;
+
+fn point((x, y): Vertex) -> String {
+    format!("{:.1},{:.1}", x as f64 * UNIT_X, y as f64 * UNIT_Y)
+}
+
+fn centre(hex: &Coordinate) -> (f64, f64) {
+    let (x, y) = hex.vertices()[0];
+    ((x - 2) as f64 * UNIT_X, y as f64 * UNIT_Y)
+}
+
+fn outline(vertices: &[Vertex]) -> String {
+    let points = vertices
+        .iter()
+        .map(|v| point(*v))
+        .collect::<Vec<String>>()
+        .join(" L ");
+    format!("M {points} Z")
+}
+
+/// A colour for an allegiance code that stays the same across runs and Rust releases.
+pub fn allegiance_colour(allegiance: &str) -> String {
+    let mut hasher = HashAlgo::new();
+    hasher.write(allegiance.as_bytes());
+    format!("hsl({}, 70%, 50%)", hasher.finish() % 360)
+}
+
+fn grid() -> String {
+    let hexes = (1..=SECTOR_ROWS)
+        .flat_map(|row| (1..=SECTOR_COLS).map(move |col| Coordinate::new(row, col)))
+        .map(|hex| outline(&hex.vertices()))
+        .collect::<Vec<String>>()
+        .join(" ");
+    format!("<path stroke=\"#444\" fill=\"none\" d=\"{hexes}\"/>")
+}
+
+fn border(border: &Border) -> String {
+    let colour = allegiance_colour(&border.allegiance);
+    let path = border
+        .loops
+        .iter()
+        .map(|l| outline(&l.vertices))
+        .collect::<Vec<String>>()
+        .join(" ");
+    format!(
+        "<path class=\"border\" data-allegiance=\"{}\" d=\"{path}\" fill=\"{colour}\" fill-opacity=\"0.15\" fill-rule=\"evenodd\" stroke=\"{colour}\" stroke-width=\"2\"/>",
+        border.allegiance
+    )
+}
+
+fn ring(x: f64, y: f64, colour: &str) -> String {
+    format!("<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"6\" fill=\"none\" stroke=\"{colour}\"/>")
+}
+
+fn system(sys: &System) -> String {
+    let (x, y) = centre(&sys.location);
+    let fill = match sys.mainworld.travel_zone {
+        TravelZone::Red => "red",
+        TravelZone::Amber => "orange",
+        TravelZone::Green => "white",
+    };
+    let facilities = sys.mainworld.port.facilities();
+    // Highports get a ring, and refuelling ports a blue one
+    let ring = match (facilities.highport, facilities.fuel) {
+        (false, _) => String::new(),
+        (true, FuelQuality::Refined) => ring(x, y, "#4af"),
+        (true, _) => ring(x, y, "#ccc"),
+    };
+    format!(
+        "{ring}<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"4\" fill=\"{fill}\"/><text x=\"{x:.1}\" y=\"{:.1}\" fill=\"#ccc\" font-size=\"7\" text-anchor=\"middle\">{}</text>",
+        y - 8.0,
+        sys.mainworld
+    )
+}
+
+fn deep_space(hex: &DeepSpace) -> String {
+    let (x, y) = centre(&hex.location);
+    let nebula = match hex.features.contains(&DeepSpaceFeature::Nebula) {
+        true => format!(
+            "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"14\" fill=\"#a6f\" fill-opacity=\"0.2\"/>"
+        ),
+        false => String::new(),
+    };
+    // Refuelling points are blue, like refuelling starports
+    let colour = if hex.has_fuel() { "#4af" } else { "#888" };
+    format!(
+        "{nebula}<rect x=\"{:.1}\" y=\"{:.1}\" width=\"4\" height=\"4\" fill=\"{colour}\"><title>{}</title></rect>",
+        x - 2.0,
+        y - 2.0,
+        escape_html(&hex.to_string())
+    )
+}
+
+/// Draws the sector's hex grid, systems and polity borders as an SVG image.
+pub fn sector_map(sector: &Sector) -> String {
+    let width = (3 * SECTOR_ROWS + 3) as f64 * UNIT_X;
+    let height = (2 * SECTOR_COLS + 2) as f64 * UNIT_Y;
+
+    let elements = [
+        String::from("<rect width=\"100%\" height=\"100%\" fill=\"black\"/>"),
+        grid(),
+    ]
+    .into_iter()
+    .chain(sector.borders().iter().map(border))
+    .chain(sector.deep_space.iter().map(deep_space))
+    .chain(sector.systems.iter().map(system))
+    .collect::<Vec<String>>()
+    .join("\n");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width:.0} {height:.0}\" font-family=\"sans-serif\">\n{elements}\n</svg>"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allegiance_colour_is_pinned() {
+        assert_eq!(allegiance_colour("ImDd"), "hsl(210, 70%, 50%)");
+    }
+}